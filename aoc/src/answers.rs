@@ -0,0 +1,157 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::{Answer, Part};
+use thiserror::Error;
+use toml::{Table, Value};
+
+/// The default location of the answers file, next to the workspace manifest.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read answers file {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("cannot write answers file {}: {source}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("invalid answers file {}: {message}", path.display())]
+    Invalid { path: PathBuf, message: String },
+}
+
+/// The known answers of each day and part, stored as a TOML file with one table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 66306
+/// part2 = 195292
+/// ```
+///
+/// Numbers are stored as integers and text answers as strings.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<Part, Answer>>,
+}
+
+impl Answers {
+    /// Loads the answers from `path`. A missing file is treated as a file without any answers.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(Error::Read {
+                    path: path.to_owned(),
+                    source,
+                })
+            }
+        };
+
+        Self::parse(&text).map_err(|message| Error::Invalid {
+            path: path.to_owned(),
+            message,
+        })
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let table: Table = text
+            .parse()
+            .map_err(|err: toml::de::Error| err.to_string())?;
+
+        let mut answers = Self::default();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .filter(|n| (1..=25).contains(n))
+                .ok_or_else(|| format!("expected a table named day1 to day25, found {key:?}"))?;
+            let Value::Table(parts) = value else {
+                return Err(format!("expected {key} to be a table"));
+            };
+
+            for (key, value) in parts {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => {
+                        return Err(format!(
+                            "expected part1 or part2 in day{day}, found {key:?}"
+                        ))
+                    }
+                };
+                let answer = match value {
+                    Value::Integer(n) => Answer::Number(n),
+                    Value::String(s) => Answer::Text(s),
+                    _ => return Err(format!("expected a number or a string for day{day}.{key}")),
+                };
+                answers.set(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_toml()).map_err(|source| Error::Write {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    fn to_toml(&self) -> String {
+        let mut table = Table::new();
+        for (day, parts) in &self.days {
+            let parts = parts
+                .iter()
+                .map(|(part, answer)| {
+                    let value = match answer {
+                        Answer::Number(n) => Value::Integer(*n),
+                        Answer::Text(s) => Value::String(s.clone()),
+                    };
+                    (format!("part{part}"), value)
+                })
+                .collect();
+            table.insert(format!("day{day}"), Value::Table(parts));
+        }
+
+        table.to_string()
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?.get(&part)
+    }
+
+    /// Stores the answer of a part, replacing a previously known answer.
+    pub fn set(&mut self, day: u8, part: Part, answer: Answer) {
+        self.days.entry(day).or_default().insert(part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(10, Part::One, Answer::Number(14560));
+        answers.set(10, Part::Two, Answer::Text("#..#\n.##.".to_owned()));
+        answers.set(2, Part::One, Answer::Number(-3));
+
+        let text = answers.to_toml();
+
+        // days are written in numerical order
+        assert!(text.find("[day2]").unwrap() < text.find("[day10]").unwrap());
+
+        let parsed = Answers::parse(&text).unwrap();
+        assert_eq!(parsed.days, answers.days);
+    }
+
+    #[test]
+    fn invalid_key() {
+        let error = Answers::parse("[day26]\npart1 = 1\n").unwrap_err();
+        assert!(error.contains("day26"), "{error}");
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fs::File, io, path::Path, time::Duration};
+
+use common::bench::{Measurement, Samples};
+use serde::Serialize;
+
+/// The measurements of all benchmarked days.
+#[derive(Default)]
+pub struct Report {
+    days: Vec<(u8, Measurement)>,
+}
+
+/// A single phase of a day, as written to the machine-readable outputs.
+#[derive(Serialize)]
+struct Row {
+    day: u8,
+    phase: &'static str,
+    runs: usize,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    max_ns: u128,
+}
+
+impl Row {
+    fn new(day: u8, phase: &'static str, samples: &Samples) -> Self {
+        Self {
+            day,
+            phase,
+            runs: samples.runs(),
+            min_ns: samples.min().as_nanos(),
+            median_ns: samples.median().as_nanos(),
+            mean_ns: samples.mean().as_nanos(),
+            max_ns: samples.max().as_nanos(),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+impl Report {
+    pub fn add(&mut self, day: u8, measurement: Measurement) {
+        self.days.push((day, measurement));
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (day, measurement) in &self.days {
+            rows.push(Row::new(*day, "parse", &measurement.parse));
+            rows.push(Row::new(*day, "part1", &measurement.part1));
+            if let Some(part2) = &measurement.part2 {
+                rows.push(Row::new(*day, "part2", part2));
+            }
+        }
+
+        rows
+    }
+
+    /// Prints the median timings of each day as a table.
+    pub fn print_table(&self) {
+        println!(
+            "{:>3} {:>12} {:>12} {:>12} {:>12}",
+            "Day", "Parse", "Part 1", "Part 2", "Total"
+        );
+
+        let mut total = Duration::ZERO;
+        for (day, measurement) in &self.days {
+            let parse = measurement.parse.median();
+            let part1 = measurement.part1.median();
+            let part2 = measurement.part2.as_ref().map(Samples::median);
+            let day_total = parse + part1 + part2.unwrap_or_default();
+            total += day_total;
+
+            println!(
+                "{day:>3} {:>12} {:>12} {:>12} {:>12}",
+                format_duration(parse),
+                format_duration(part1),
+                part2.map_or_else(|| "-".to_owned(), format_duration),
+                format_duration(day_total),
+            );
+        }
+
+        if self.days.len() > 1 {
+            // only the total column
+            println!("{:>55}", format_duration(total));
+        }
+    }
+
+    pub fn write_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = csv::Writer::from_path(path)?;
+        for row in self.rows() {
+            writer.serialize(row)?;
+        }
+
+        writer.flush()
+    }
+
+    pub fn write_json(&self, path: &Path) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, &self.rows())?;
+        Ok(())
+    }
+}
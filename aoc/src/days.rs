@@ -0,0 +1,78 @@
+use common::DynSolution;
+use rand_chacha::ChaCha8Rng;
+
+pub struct Day {
+    pub number: u8,
+    pub default_input: &'static str,
+    pub solution: Box<dyn DynSolution + Sync>,
+    /// Generates a random input of the given size.
+    pub generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+macro_rules! day {
+    ($number:literal, $day:ident, $solution:expr) => {
+        Day {
+            number: $number,
+            default_input: concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../",
+                stringify!($day),
+                "/input"
+            ),
+            solution: Box::new($solution),
+            generate: |rng, size| $day::generate::generate(rng, size),
+        }
+    };
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        day!(1, day1, day1::Day1),
+        day!(2, day2, day2::Day2::default()),
+        day!(3, day3, day3::Day3::default()),
+        day!(4, day4, day4::Day4),
+        day!(5, day5, day5::Day5::default()),
+        day!(6, day6, day6::Day6),
+        day!(7, day7, day7::Day7),
+        day!(8, day8, day8::Day8),
+        day!(9, day9, day9::Day9),
+        day!(10, day10, day10::Day10),
+        day!(11, day11, day11::Day11),
+        day!(12, day12, day12::Day12),
+        day!(13, day13, day13::Day13),
+        day!(14, day14, day14::Day14),
+        day!(15, day15, day15::Day15::default()),
+        day!(16, day16, day16::Day16),
+        day!(17, day17, day17::Day17),
+        day!(18, day18, day18::Day18),
+        #[cfg(feature = "cbc")]
+        day!(19, day19, day19::Day19),
+        day!(20, day20, day20::Day20),
+        day!(21, day21, day21::Day21),
+        day!(22, day22, day22::Day22::default()),
+        day!(23, day23, day23::Day23),
+        day!(24, day24, day24::Day24),
+        day!(25, day25, day25::Day25),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn line_endings_and_trailing_whitespace_are_ignored() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for day in all() {
+            let input = (day.generate)(&mut rng, 8);
+            let mangled = format!("{}\r\n \r\n", input.replace('\n', " \t\r\n"));
+
+            let expected = day.solution.solve(&input, &Part::ALL).unwrap();
+            let answers = day.solution.solve(&mangled, &Part::ALL).unwrap();
+            assert_eq!(answers, expected, "day {}", day.number);
+        }
+    }
+}
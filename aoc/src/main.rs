@@ -0,0 +1,537 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::Instant,
+};
+
+use answers::Answers;
+use bench::Report;
+use clap::{Parser, Subcommand};
+use common::{
+    bench::{Options, TimedAnswer},
+    Answer, Part,
+};
+use cpu_time::ProcessTime;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use days::Day;
+
+mod answers;
+mod bench;
+mod days;
+mod output;
+mod runner;
+mod visualization;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Number of threads for solving days in parallel (defaults to the number of CPUs)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+
+    /// Write diagnostics matching this filter to stderr, e.g. "info" or "day16=debug"
+    #[arg(long, global = true, env = common::log::ENV_VAR)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a single day or of all days, and show how long they took if several days are run
+    Run {
+        /// The day to run (1-25), or "all"
+        day: Selection,
+
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file ("-" for stdin) instead of the day's input file
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: output::Format,
+
+        #[command(flatten)]
+        visualization: visualization::Options,
+    },
+    /// Measure how long parsing and solving each part takes (one day after another, to not skew the timings)
+    Bench {
+        /// The day to benchmark (1-25), or "all"
+        day: Selection,
+
+        /// Number of untimed runs before measuring
+        #[arg(long, default_value_t = 1)]
+        warmup: u32,
+
+        /// Number of timed runs
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Read the puzzle input from this file ("-" for stdin) instead of the day's input file
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Also write the timings of all runs as CSV to this file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+
+        /// Also write the timings of all runs as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Compare the answers of a single day or of all days with the known answers
+    Verify {
+        /// The day to verify (1-25), or "all"
+        day: Selection,
+
+        /// The file with the known answers
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Run a single day or all days and store their answers as the known answers
+    Record {
+        /// The day to record (1-25), or "all"
+        day: Selection,
+
+        /// The file with the known answers, which is created if it does not exist
+        #[arg(long, default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+    },
+    /// Print a report about a day's input beyond the answers, for the days that have one
+    Analyze {
+        /// The day to analyze (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Read the puzzle input from this file ("-" for stdin) instead of the day's input file
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Print a random puzzle input for a day
+    Generate {
+        /// The day to generate an input for (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The seed of the random generator, the same seed always yields the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// The size of the input, usually the number of lines or items (see the day's generator for details)
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        match s.parse() {
+            Ok(day @ 1..=25) => Ok(Self::Day(day)),
+            _ => Err(format!(
+                "expected a day between 1 and 25 or \"all\", got \"{s}\""
+            )),
+        }
+    }
+}
+
+fn print_answer(part: Part, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        // multi-line answers (like the CRT output of day 10) start on their own line
+        println!("  Part {part}:\n{answer}");
+    } else {
+        println!("  Part {part}: {answer}");
+    }
+}
+
+/// Returns the selected days, or `None` (after printing an error) if the selection is invalid.
+fn select<'a>(
+    days: &'a [Day],
+    selection: Selection,
+    input: Option<&PathBuf>,
+) -> Option<Vec<&'a Day>> {
+    match selection {
+        Selection::All => {
+            if input.is_some() {
+                eprintln!("error: --input can only be used when running a single day");
+                return None;
+            }
+
+            Some(days.iter().collect())
+        }
+        Selection::Day(number) => match days.iter().find(|d| d.number == number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("error: day {number} is not available in this build");
+                None
+            }
+        },
+    }
+}
+
+fn run(
+    days: &[&Day],
+    part: Option<Part>,
+    input: Option<&PathBuf>,
+    format: output::Format,
+    visualization: &visualization::Options,
+) -> bool {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let (wall, cpu) = (Instant::now(), ProcessTime::now());
+    let outcomes = runner::solve(days, &parts, input, Some(visualization));
+    let (wall, cpu) = (wall.elapsed(), cpu.elapsed());
+
+    // the answers are only printed once all days are done, so that they are always in order
+    // keep going after a failing day, so that a single missing input does not hide all other results
+    let mut success = true;
+    for (day, outcome) in days.iter().zip(&outcomes) {
+        if format == output::Format::Text {
+            println!("Day {}", day.number);
+        }
+
+        match outcome {
+            Ok(timed) if format == output::Format::Text => {
+                if timed.answers.is_empty() {
+                    println!("  no puzzle");
+                }
+
+                for answer in &timed.answers {
+                    print_answer(answer.part, &answer.answer);
+                    if !answer.extras.is_empty() {
+                        let extras: Vec<_> = answer
+                            .extras
+                            .iter()
+                            .map(|(name, value)| format!("{name}={value}"))
+                            .collect();
+                        println!("    ({})", extras.join(", "));
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+            }
+        }
+    }
+
+    if format == output::Format::Text {
+        if days.len() > 1 {
+            println!();
+            runner::print_summary(days, &outcomes, wall, cpu);
+        }
+    } else {
+        let solved = days
+            .iter()
+            .zip(&outcomes)
+            .filter_map(|(day, outcome)| Some((day.number, outcome.as_ref().ok()?)));
+        if let Err(err) = output::write(format, solved) {
+            eprintln!("error: cannot write the answers: {err}");
+            success = false;
+        }
+    }
+
+    success
+}
+
+fn bench(
+    days: &[&Day],
+    options: &Options,
+    input: Option<&PathBuf>,
+    csv: Option<&PathBuf>,
+    json: Option<&PathBuf>,
+) -> bool {
+    let mut success = true;
+    let mut report = Report::default();
+    for day in days {
+        let input = match runner::read_input(day, input) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+                continue;
+            }
+        };
+
+        match day.solution.bench(&input, options) {
+            Ok(measurement) => report.add(day.number, measurement),
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+            }
+        }
+    }
+
+    report.print_table();
+
+    if let Some(path) = csv {
+        if let Err(err) = report.write_csv(path) {
+            eprintln!("error: cannot write {}: {err}", path.display());
+            success = false;
+        }
+    }
+
+    if let Some(path) = json {
+        if let Err(err) = report.write_json(path) {
+            eprintln!("error: cannot write {}: {err}", path.display());
+            success = false;
+        }
+    }
+
+    success
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    /// There is no known answer to compare with.
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Unknown => "UNKNOWN",
+        };
+        // pad, so that the status can be aligned in the report table
+        f.pad(status)
+    }
+}
+
+fn verify(days: &[&Day], known: &Answers) -> bool {
+    let mut success = true;
+    let mut mismatches = Vec::new();
+    let mut counts = [0; 3];
+
+    println!(
+        "{:>3} {:<8} {:<8} {:>12}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+
+    for (day, outcome) in days.iter().zip(runner::solve(days, &Part::ALL, None, None)) {
+        let timed = match outcome {
+            Ok(timed) => timed,
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+                continue;
+            }
+        };
+        let elapsed = timed.total().wall;
+
+        let mut statuses = ["-".to_owned(), "-".to_owned()];
+        for TimedAnswer { part, answer, .. } in timed.answers {
+            let status = match known.get(day.number, part) {
+                Some(expected) if *expected == answer => Status::Pass,
+                Some(expected) => {
+                    mismatches.push((day.number, part, expected.clone(), answer));
+                    Status::Fail
+                }
+                None => Status::Unknown,
+            };
+
+            counts[status as usize] += 1;
+            statuses[part as usize] = status.to_string();
+        }
+
+        println!(
+            "{:>3} {:<8} {:<8} {:>12}",
+            day.number,
+            statuses[0],
+            statuses[1],
+            bench::format_duration(elapsed)
+        );
+    }
+
+    for (day, part, expected, answer) in &mismatches {
+        println!("\nDay {day}");
+        print_answer(*part, answer);
+        let expected = expected.to_string();
+        if expected.contains('\n') {
+            println!("  Expected:\n{expected}");
+        } else {
+            println!("  Expected: {expected}");
+        }
+    }
+
+    let [passed, failed, unknown] = counts;
+    println!("\n{passed} passed, {failed} failed, {unknown} unknown");
+
+    success && failed == 0
+}
+
+fn record(days: &[&Day], path: &Path) -> bool {
+    let mut known = match Answers::load(path) {
+        Ok(known) => known,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return false;
+        }
+    };
+
+    let mut success = true;
+    for (day, outcome) in days.iter().zip(runner::solve(days, &Part::ALL, None, None)) {
+        let timed = match outcome {
+            Ok(timed) => timed,
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+                continue;
+            }
+        };
+
+        println!("Day {}", day.number);
+        for TimedAnswer { part, answer, .. } in timed.answers {
+            print_answer(part, &answer);
+            known.set(day.number, part, answer);
+        }
+    }
+
+    // store the answers of the days that succeeded even if others failed
+    if let Err(err) = known.save(path) {
+        eprintln!("error: {err}");
+        return false;
+    }
+
+    success
+}
+
+fn analyze(day: &Day, input: Option<&PathBuf>) -> bool {
+    let analysis = runner::read_input(day, input)
+        .map_err(runner::Error::from)
+        .and_then(|input| Ok(day.solution.analysis(&input)?));
+
+    match analysis {
+        Ok(Some(report)) => {
+            print!("{report}");
+            true
+        }
+        Ok(None) => {
+            eprintln!("error: day {} has no analysis", day.number);
+            false
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let days = days::all();
+
+    if let Err(err) = common::log::init(cli.log.as_deref()) {
+        eprintln!("error: invalid log filter: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(jobs) = cli.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs.into())
+            .build_global()
+            .expect("the thread pool is only configured once");
+    }
+
+    let success = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            visualization,
+        } => {
+            let part = part.map(|p| if p == 1 { Part::One } else { Part::Two });
+            let Some(days) = select(&days, day, input.as_ref()) else {
+                return ExitCode::FAILURE;
+            };
+
+            run(&days, part, input.as_ref(), format, &visualization)
+        }
+        Command::Bench {
+            day,
+            warmup,
+            runs,
+            input,
+            csv,
+            json,
+        } => {
+            let Some(days) = select(&days, day, input.as_ref()) else {
+                return ExitCode::FAILURE;
+            };
+
+            let options = Options { warmup, runs };
+            bench(&days, &options, input.as_ref(), csv.as_ref(), json.as_ref())
+        }
+        Command::Verify { day, answers } => {
+            let Some(days) = select(&days, day, None) else {
+                return ExitCode::FAILURE;
+            };
+
+            match Answers::load(&answers) {
+                Ok(known) => verify(&days, &known),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    false
+                }
+            }
+        }
+        Command::Record { day, answers } => {
+            let Some(days) = select(&days, day, None) else {
+                return ExitCode::FAILURE;
+            };
+
+            record(&days, &answers)
+        }
+        Command::Analyze { day, input } => {
+            let Some(days) = select(&days, Selection::Day(day), input.as_ref()) else {
+                return ExitCode::FAILURE;
+            };
+
+            analyze(days[0], input.as_ref())
+        }
+        Command::Generate { day, seed, size } => {
+            let Some(days) = select(&days, Selection::Day(day), None) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut rng = ChaCha8Rng::seed_from_u64(seed);
+            print!("{}", (days[0].generate)(&mut rng, size));
+            true
+        }
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,155 @@
+//! Machine-readable outputs of the answers, with one record per day and part.
+
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use common::{
+    bench::{Timed, TimedAnswer},
+    Answer,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Readable answers and a timing summary
+    #[default]
+    Text,
+    /// A JSON array with one object per day and part
+    Json,
+    /// CSV with one row per day and part, where the extras are written as "name=value" pairs separated by ";"
+    Csv,
+}
+
+/// The kind of an answer, as written to the outputs.
+fn kind(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Number(_) => "number",
+        Answer::Text(_) => "text",
+    }
+}
+
+fn json_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => Value::from(*n),
+        Answer::Text(s) => Value::from(s.as_str()),
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRecord {
+    day: u8,
+    part: u8,
+    answer: Value,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    wall_ns: u128,
+    cpu_ns: u128,
+    extras: Map<String, Value>,
+}
+
+impl JsonRecord {
+    fn new(day: u8, answer: &TimedAnswer) -> Self {
+        Self {
+            day,
+            part: answer.part as u8 + 1,
+            answer: json_value(&answer.answer),
+            kind: kind(&answer.answer),
+            wall_ns: answer.timing.wall.as_nanos(),
+            cpu_ns: answer.timing.cpu.as_nanos(),
+            extras: answer
+                .extras
+                .iter()
+                .map(|(name, value)| (name.to_string(), json_value(value)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CsvRecord {
+    day: u8,
+    part: u8,
+    answer: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    wall_ns: u128,
+    cpu_ns: u128,
+    extras: String,
+}
+
+impl CsvRecord {
+    fn new(day: u8, answer: &TimedAnswer) -> Self {
+        let extras: Vec<_> = answer
+            .extras
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+
+        Self {
+            day,
+            part: answer.part as u8 + 1,
+            answer: answer.answer.to_string(),
+            kind: kind(&answer.answer),
+            wall_ns: answer.timing.wall.as_nanos(),
+            cpu_ns: answer.timing.cpu.as_nanos(),
+            extras: extras.join(";"),
+        }
+    }
+}
+
+/// Writes the records of the solved days to stdout. Does nothing for the text format.
+pub fn write<'a>(
+    format: Format,
+    days: impl IntoIterator<Item = (u8, &'a Timed)>,
+) -> io::Result<()> {
+    let answers = days
+        .into_iter()
+        .flat_map(|(day, timed)| timed.answers.iter().map(move |answer| (day, answer)));
+
+    match format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            let records: Vec<_> = answers
+                .map(|(day, answer)| JsonRecord::new(day, answer))
+                .collect();
+
+            let mut stdout = io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &records)?;
+            writeln!(stdout)
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout().lock());
+            for (day, answer) in answers {
+                writer.serialize(CsvRecord::new(day, answer))?;
+            }
+
+            writer.flush()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{bench::Timing, Part};
+
+    use super::*;
+
+    #[test]
+    fn records() {
+        let answer = TimedAnswer {
+            part: Part::Two,
+            answer: 42.into(),
+            timing: Timing::default(),
+            extras: vec![("x", 3.into()), ("name", "abc".into())],
+        };
+
+        let csv = CsvRecord::new(7, &answer);
+        assert_eq!((csv.part, csv.extras.as_str()), (2, "x=3;name=abc"));
+
+        let json = serde_json::to_value(JsonRecord::new(7, &answer)).unwrap();
+        assert_eq!(json["answer"], 42);
+        assert_eq!(json["type"], "number");
+        assert_eq!(json["extras"]["name"], "abc");
+    }
+}
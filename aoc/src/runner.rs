@@ -0,0 +1,129 @@
+//! Solving several days at once on a thread pool.
+
+use std::{io, path::PathBuf, time::Duration};
+
+use common::{
+    bench::{Timed, Timing},
+    input,
+    parse::ParseError,
+    Part, SolveError,
+};
+use rayon::prelude::*;
+use thiserror::Error;
+
+use crate::{bench::format_duration, days::Day, visualization};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Input(#[from] input::Error),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+    #[error("cannot create the frame output: {0}")]
+    CreateFrames(io::Error),
+    #[error("cannot write frames: {0}")]
+    WriteFrames(io::Error),
+}
+
+impl From<common::Error> for Error {
+    fn from(error: common::Error) -> Self {
+        match error {
+            common::Error::Parse(error) => Self::Parse(error),
+            common::Error::Solve(error) => Self::Solve(error),
+        }
+    }
+}
+
+/// The answers of a day and their timings, or why the day could not be solved.
+pub type Outcome = Result<Timed, Error>;
+
+/// Reads the input from `input`, or from the day's input file if no path is given.
+pub fn read_input(day: &Day, input: Option<&PathBuf>) -> Result<String, input::Error> {
+    match input {
+        Some(path) => input::read(path),
+        None => input::read(day.default_input),
+    }
+}
+
+/// Solves the given parts of each day and returns the outcomes in the order of `days`.
+///
+/// The days run in parallel on the global thread pool, unless their simulations are visualized (the frames of
+/// different days would be mixed up otherwise).
+pub fn solve(
+    days: &[&Day],
+    parts: &[Part],
+    input: Option<&PathBuf>,
+    visualization: Option<&visualization::Options>,
+) -> Vec<Outcome> {
+    let solve_day = |day: &&Day| solve_day(day, parts, input, visualization);
+    if visualization.is_some_and(visualization::Options::is_enabled) {
+        days.iter().map(solve_day).collect()
+    } else {
+        days.par_iter().map(solve_day).collect()
+    }
+}
+
+fn solve_day(
+    day: &Day,
+    parts: &[Part],
+    input: Option<&PathBuf>,
+    visualization: Option<&visualization::Options>,
+) -> Outcome {
+    let input = read_input(day, input)?;
+    let sink = match visualization {
+        Some(visualization) => visualization
+            .sink(day.number)
+            .map_err(Error::CreateFrames)?,
+        None => None,
+    };
+
+    match sink {
+        Some(sink) => {
+            let (result, status) =
+                visualize::record(sink, || day.solution.solve_timed(&input, parts));
+            status.map_err(Error::WriteFrames)?;
+            Ok(result?)
+        }
+        None => Ok(day.solution.solve_timed(&input, parts)?),
+    }
+}
+
+/// Prints the wall and CPU time of each phase of the days as a table, followed by the totals of the whole run.
+///
+/// The CPU time of a phase only covers the thread that ran it, while the total CPU time covers all threads.
+pub fn print_summary(days: &[&Day], outcomes: &[Outcome], wall: Duration, cpu: Duration) {
+    println!(
+        "{:>3} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Parse wall", "Parse CPU", "Part 1 wall", "Part 1 CPU", "Part 2 wall", "Part 2 CPU"
+    );
+
+    let format = |timing: Option<Timing>| match timing {
+        Some(timing) => (format_duration(timing.wall), format_duration(timing.cpu)),
+        None => ("-".to_owned(), "-".to_owned()),
+    };
+
+    for (day, outcome) in days.iter().zip(outcomes) {
+        let Ok(timed) = outcome else {
+            println!("{:>3} {:>12}", day.number, "failed");
+            continue;
+        };
+
+        let parse = format(Some(timed.parse));
+        let part1 = format(timed.timing(Part::One));
+        let part2 = format(timed.timing(Part::Two));
+        println!(
+            "{:>3} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            day.number, parse.0, parse.1, part1.0, part1.1, part2.0, part2.1
+        );
+    }
+
+    let threads = rayon::current_num_threads();
+    println!(
+        "\nTotal: {} wall, {} CPU on {threads} thread{}",
+        format_duration(wall),
+        format_duration(cpu),
+        if threads == 1 { "" } else { "s" }
+    );
+}
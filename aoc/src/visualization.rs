@@ -0,0 +1,66 @@
+use std::{fs, io, path::PathBuf, time::Duration};
+
+use clap::{Args, ValueEnum};
+use visualize::{Ascii, FrameSink, Gif, Ppm};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Kind {
+    /// Show the frames in the terminal
+    Ascii,
+    /// Write each frame as a numbered PPM image
+    Ppm,
+    /// Write all frames as an animated GIF
+    Gif,
+}
+
+#[derive(Args)]
+pub struct Options {
+    /// Show or save the frames of the simulations of the selected days
+    #[arg(long, value_enum)]
+    visualize: Option<Kind>,
+
+    /// Directory for the PPM and GIF outputs, which is created if needed
+    #[arg(long, default_value = "frames")]
+    frames: PathBuf,
+
+    /// How long each frame is shown in the terminal and in GIFs, in milliseconds
+    #[arg(long, default_value_t = 100)]
+    frame_delay: u64,
+
+    /// Size of a single character of a frame in pixels in the PPM and GIF outputs
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+    scale: u16,
+}
+
+impl Options {
+    pub fn is_enabled(&self) -> bool {
+        self.visualize.is_some()
+    }
+
+    /// Creates the sink for the frames of a day, or returns `None` if the simulations should not be visualized.
+    pub fn sink(&self, day: u8) -> io::Result<Option<Box<dyn FrameSink>>> {
+        let Some(kind) = self.visualize else {
+            return Ok(None);
+        };
+
+        let delay = Duration::from_millis(self.frame_delay);
+        let scale = self.scale as usize;
+        let sink: Box<dyn FrameSink> = match kind {
+            Kind::Ascii => Box::new(Ascii::new(io::stdout(), delay)),
+            Kind::Ppm => {
+                fs::create_dir_all(&self.frames)?;
+                Box::new(Ppm::new(&self.frames, format!("day{day}"), scale))
+            }
+            Kind::Gif => {
+                fs::create_dir_all(&self.frames)?;
+                Box::new(Gif::new(
+                    self.frames.join(format!("day{day}.gif")),
+                    scale,
+                    delay,
+                ))
+            }
+        };
+
+        Ok(Some(sink))
+    }
+}
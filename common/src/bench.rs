@@ -0,0 +1,151 @@
+use std::{
+    hint::black_box,
+    ops::Add,
+    time::{Duration, Instant},
+};
+
+use cpu_time::ThreadTime;
+
+use crate::{extras::Extras, Answer, Part};
+
+/// How often the phases of a day are run when benchmarking.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Runs whose timings are discarded, e.g. to fill caches.
+    pub warmup: u32,
+    /// Runs whose timings are recorded.
+    pub runs: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { warmup: 1, runs: 5 }
+    }
+}
+
+/// The recorded timings of a single phase.
+#[derive(Debug, Clone)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    pub fn runs(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.0.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.0.len() {
+            0 => Duration::ZERO,
+            n => self.0.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.0.clone();
+        sorted.sort_unstable();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        }
+    }
+}
+
+/// The timings of parsing the input and of both parts of a day.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub parse: Samples,
+    pub part1: Samples,
+    /// `None` if the day has no second part.
+    pub part2: Option<Samples>,
+}
+
+/// How long a single run of a phase took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timing {
+    /// The elapsed real time.
+    pub wall: Duration,
+    /// The CPU time of the thread that ran the phase. Work that the phase hands to other threads is not included.
+    pub cpu: Duration,
+}
+
+impl Add for Timing {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            wall: self.wall + other.wall,
+            cpu: self.cpu + other.cpu,
+        }
+    }
+}
+
+/// The answer of a part together with how long it took.
+#[derive(Debug, Clone)]
+pub struct TimedAnswer {
+    pub part: Part,
+    pub answer: Answer,
+    pub timing: Timing,
+    /// The values the part computed besides the answer.
+    pub extras: Extras,
+}
+
+/// The answers of a day together with the time each phase took.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub parse: Timing,
+    pub answers: Vec<TimedAnswer>,
+}
+
+impl Timed {
+    /// The time of parsing and of all parts together.
+    pub fn total(&self) -> Timing {
+        self.answers
+            .iter()
+            .fold(self.parse, |total, answer| total + answer.timing)
+    }
+
+    pub fn timing(&self, part: Part) -> Option<Timing> {
+        self.answers
+            .iter()
+            .find(|answer| answer.part == part)
+            .map(|answer| answer.timing)
+    }
+}
+
+/// Runs `f` once and measures how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Timing) {
+    let cpu = ThreadTime::now();
+    let wall = Instant::now();
+    let result = f();
+    let timing = Timing {
+        wall: wall.elapsed(),
+        cpu: cpu.elapsed(),
+    };
+
+    (result, timing)
+}
+
+/// Runs `f` `options.warmup + options.runs` times and records the durations of the last `options.runs` calls.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> T) -> Samples {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..options.runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Samples(samples)
+}
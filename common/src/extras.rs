@@ -0,0 +1,53 @@
+//! Values that a part computes besides its answer, like the position of the distress beacon of day 15, for the
+//! machine-readable outputs.
+//!
+//! Parts report them with [`add`], which only stores them while the part runs inside [`collect`], so reporting them
+//! costs almost nothing otherwise.
+
+use std::cell::RefCell;
+
+use crate::Answer;
+
+/// Named values in the order they were added.
+pub type Extras = Vec<(&'static str, Answer)>;
+
+thread_local! {
+    static EXTRAS: RefCell<Option<Extras>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns its result together with all values that were added on the current thread in the meantime.
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Extras) {
+    let previous = EXTRAS.with(|e| e.replace(Some(Vec::new())));
+    let result = f();
+    let extras = EXTRAS.with(|e| e.replace(previous)).unwrap_or_default();
+
+    (result, extras)
+}
+
+/// Adds a value to the extras of the running part, if they are collected.
+pub fn add(name: &'static str, value: impl Into<Answer>) {
+    EXTRAS.with(|e| {
+        if let Some(extras) = e.borrow_mut().as_mut() {
+            extras.push((name, value.into()));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_collected_inside() {
+        add("ignored", 1);
+        let (result, extras) = collect(|| {
+            add("x", 3);
+            add("name", "abc");
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert_eq!(extras, [("x", 3.into()), ("name", "abc".into())]);
+        assert_eq!(collect(|| ()).1, []);
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("cannot read input file {}: {source}", path.display())]
+    File { path: PathBuf, source: io::Error },
+    #[error("cannot read input from stdin: {0}")]
+    Stdin(io::Error),
+}
+
+/// Reads the puzzle input from the given path, or from stdin if the path is `-`.
+pub fn read(path: impl AsRef<Path>) -> Result<String, Error> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(Error::Stdin)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|source| Error::File {
+            path: path.to_owned(),
+            source,
+        })
+    }
+}
+
+/// Brings an input into the form the parsers expect, so that inputs saved by different editors yield the same answers.
+///
+/// Line endings become `\n`, whitespace at the end of each line and blank lines at the end of the input are removed,
+/// and every line (including the last one) ends with a newline.
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let len = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |i| i + 1);
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in &lines[..len] {
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+/// Reads the puzzle input from the path given as the first command line argument, falling back to `default` if there
+/// is no argument.
+pub fn from_args(default: impl AsRef<Path>) -> Result<String, Error> {
+    match std::env::args_os().nth(1) {
+        Some(path) => read(path),
+        None => read(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+    }
+
+    #[test]
+    fn normalize_whitespace() {
+        assert_eq!(normalize("  a \t\n b\r\n\n  \n\n"), "  a\n b\n");
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize(""), "");
+    }
+}
@@ -0,0 +1,43 @@
+use std::process::ExitCode;
+
+pub use solution::{Answer, DynSolution, Error, Part, Solution, SolveError};
+
+pub mod bench;
+pub mod extras;
+pub mod input;
+pub mod log;
+pub mod parse;
+mod solution;
+
+/// Entry point for the binaries of the single days: reads the input given on the command line (or the default input of
+/// the day) and prints the answers of both parts.
+///
+/// Diagnostics are enabled with the filter in the [`log::ENV_VAR`] environment variable.
+pub fn run<S: Solution>(solution: S, default_input: &str) -> ExitCode {
+    if let Err(err) = log::init(std::env::var(log::ENV_VAR).ok().as_deref()) {
+        eprintln!("error: invalid {}: {err}", log::ENV_VAR);
+        return ExitCode::FAILURE;
+    }
+
+    let input = match input::from_args(default_input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solution.solve(&input, &Part::ALL) {
+        Ok(answers) => {
+            for (_, answer) in answers {
+                println!("{answer}");
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Progress messages and diagnostics of the solutions, which are written to stderr and are off by default.
+//!
+//! The days log through the macros of the `tracing` crate, so their crate name is the target of their events and a
+//! filter like `day16=debug` enables the messages of a single day. The filter syntax is the one of
+//! [`EnvFilter`](tracing_subscriber::EnvFilter), e.g. `info` for the timings of each part of all days or
+//! `info,day23=trace` for everything day 23 has to say. Closed spans are logged together with their duration.
+
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::{filter::ParseError, fmt::format::FmtSpan, EnvFilter};
+
+/// The environment variable that holds the filter if none is given otherwise.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Writes the events that match `filter` to stderr. Without a filter, nothing is logged.
+pub fn init(filter: Option<&str>) -> Result<(), ParseError> {
+    let Some(filter) = filter else {
+        return Ok(());
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_new(filter)?)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr)
+        .init();
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+//! Error reporting for malformed puzzle inputs.
+
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+/// A malformed puzzle input, pointing to the position where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub struct ParseError {
+    /// The day whose input was parsed, if known.
+    pub day: Option<u8>,
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error, starting at 1.
+    pub column: usize,
+    /// A description of what the parser expected at this position.
+    pub expected: String,
+    /// A description of what was found instead.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: "end of line".to_owned(),
+        }
+    }
+
+    /// An error for an input that ends before `line`.
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        Self::new(line, 1, expected).found("end of input")
+    }
+
+    /// An error for an unexpected character at the given position.
+    pub fn unexpected(
+        line: usize,
+        column: usize,
+        found: char,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self::new(line, column, expected).found(format!("{found:?}"))
+    }
+
+    pub fn found(mut self, found: impl Into<String>) -> Self {
+        self.found = found.into();
+        self
+    }
+
+    /// Sets the day of the error, unless it is already set.
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+/// Returns the line and column (both starting at 1) of the byte at `offset` in `input`.
+pub fn location(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset.min(input.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    (line, column)
+}
+
+/// Iterates over the lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+}
+
+/// A line of the input that is consumed from left to right, keeping track of the position for error messages.
+#[derive(Debug, Clone)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            text,
+            pos: 0,
+        }
+    }
+
+    /// The number of the line, starting at 1.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// The column of the next character, starting at 1.
+    pub fn column(&self) -> usize {
+        self.pos + 1
+    }
+
+    /// The part of the line that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// An error at the current position, describing the next token as what was found.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let rest = self.rest();
+        let error = ParseError::new(self.number, self.column(), expected);
+        match rest.chars().next() {
+            None => error,
+            Some(c) if !c.is_alphanumeric() => error.found(format!("{c:?}")),
+            Some(_) => {
+                let token = rest.split(|c: char| !c.is_alphanumeric()).next().unwrap();
+                error.found(format!("{token:?}"))
+            }
+        }
+    }
+
+    /// Consumes `literal`, or fails if the line does not continue with it.
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{literal:?}")))
+        }
+    }
+
+    /// Consumes `literal` if the line continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the next character.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.rest().chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes an optionally signed decimal integer.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+
+        let number = rest[..sign + digits]
+            .parse()
+            .map_err(|_| self.error("a number in range"))?;
+        self.pos += sign + digits;
+        Ok(number)
+    }
+
+    /// Consumes a non-empty sequence of alphanumeric characters.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("a word"));
+        }
+
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Consumes the first of the given tokens that the line continues with and returns the associated value.
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        for &(token, value) in options {
+            if self.eat(token) {
+                return Ok(value);
+            }
+        }
+
+        let expected = options
+            .iter()
+            .map(|(token, _)| format!("{token:?}"))
+            .collect::<Vec<_>>()
+            .join(" or ");
+        Err(self.error(expected))
+    }
+
+    /// Consumes the rest of the line.
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.rest();
+        self.pos = self.text.len();
+        rest
+    }
+
+    /// Fails if the line has not been consumed completely.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_tokens() {
+        let mut line = Line::new(3, "move 12 from -3");
+        assert_eq!(line.word(), Ok("move"));
+        line.expect(" ").unwrap();
+        assert_eq!(line.integer::<u32>(), Ok(12));
+        assert!(line.eat(" from "));
+        assert_eq!(line.integer::<i32>(), Ok(-3));
+        assert!(line.end().is_ok());
+    }
+
+    #[test]
+    fn error_position() {
+        let mut line = Line::new(3, "move x from 1");
+        line.expect("move ").unwrap();
+        let error = line.integer::<u32>().unwrap_err().in_day(5);
+        assert_eq!(
+            error.to_string(),
+            "day 5, line 3, column 6: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn error_at_end_of_line() {
+        let line = Line::new(1, "");
+        assert_eq!(
+            line.error("a number").to_string(),
+            "line 1, column 1: expected a number, found end of line"
+        );
+    }
+
+    #[test]
+    fn byte_location() {
+        assert_eq!(location("ab\ncd\n", 0), (1, 1));
+        assert_eq!(location("ab\ncd\n", 4), (2, 2));
+        assert_eq!(location("ab\ncd\n", 6), (3, 1));
+    }
+}
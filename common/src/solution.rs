@@ -0,0 +1,246 @@
+use std::{
+    error,
+    fmt::{self, Display},
+};
+
+use thiserror::Error;
+
+use crate::{
+    bench::{self, Measurement, Timed, TimedAnswer},
+    extras, input,
+    parse::ParseError,
+};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => n.fmt(f),
+            Self::Text(s) => s.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Number(n.try_into().expect("answer does not fit into an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_owned())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => f.write_str("1"),
+            Self::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// A part that cannot be solved although its input was parsed, e.g. because a configurable component of the solution
+/// rejects the input.
+#[derive(Debug)]
+pub struct SolveError {
+    /// The day that was solved, if known.
+    pub day: Option<u8>,
+    /// The part that was solved, if known.
+    pub part: Option<Part>,
+    /// The error of the solution, which can be downcast to the day's own error type.
+    pub error: Box<dyn error::Error + Send + Sync>,
+}
+
+impl SolveError {
+    pub fn new(error: impl Into<Box<dyn error::Error + Send + Sync>>) -> Self {
+        Self {
+            day: None,
+            part: None,
+            error: error.into(),
+        }
+    }
+
+    /// Sets the day of the error, unless it is already set.
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Sets the part of the error, unless it is already set.
+    pub fn in_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        if let Some(part) = self.part {
+            write!(f, "part {part}, ")?;
+        }
+
+        self.error.fmt(f)
+    }
+}
+
+impl error::Error for SolveError {}
+
+/// Why a day could not be solved.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Solve(#[from] SolveError),
+}
+
+/// The solution of a single day.
+///
+/// The input is parsed once and then shared between both parts.
+pub trait Solution {
+    /// The day of the puzzle (1-25).
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Returns an error if the part cannot be solved for an input that was parsed.
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Returns `None` if the puzzle has no second part (which is only the case on the last day).
+    fn part2(&self, _input: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(None)
+    }
+
+    /// A report about the input beyond the answers, like statistics or a plan, for the days that have one.
+    ///
+    /// Unlike extras, it is only computed on request, so it may take longer than solving the parts.
+    fn analysis(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe counterpart of [`Solution`], so that days with different input types can be run through the same
+/// interface.
+///
+/// Unlike [`Solution::parse`], the methods accept inputs with any line endings and trailing whitespace (see
+/// [`input::normalize`]).
+pub trait DynSolution {
+    /// Parses the input and solves the given parts, skipping parts that do not exist.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>, Error>;
+
+    /// Like [`solve`](Self::solve), but also records how long parsing and each part took.
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<Timed, Error>;
+
+    /// Measures parsing the input and solving each part separately.
+    fn bench(&self, input: &str, options: &bench::Options) -> Result<Measurement, Error>;
+
+    /// Parses the input and returns its [`Solution::analysis`].
+    fn analysis(&self, input: &str) -> Result<Option<String>, ParseError>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>, Error> {
+        let timed = self.solve_timed(input, parts)?;
+        let answers = timed
+            .answers
+            .into_iter()
+            .map(|answer| (answer.part, answer.answer))
+            .collect();
+
+        Ok(answers)
+    }
+
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<Timed, Error> {
+        let input = input::normalize(input);
+        let (input, parse) = bench::time(|| {
+            let _span = tracing::info_span!("parse", day = S::DAY).entered();
+            self.parse(&input)
+        });
+        let input = input.map_err(|e| e.in_day(S::DAY))?;
+        let mut answers = Vec::new();
+        for &part in parts {
+            let _span = tracing::info_span!("solve", day = S::DAY, %part).entered();
+            let ((answer, extras), timing) = bench::time(|| {
+                extras::collect(|| match part {
+                    Part::One => self.part1(&input).map(Some),
+                    Part::Two => self.part2(&input),
+                })
+            });
+            let answer = answer.map_err(|e| e.in_part(part).in_day(S::DAY))?;
+            if let Some(answer) = answer {
+                answers.push(TimedAnswer {
+                    part,
+                    answer,
+                    timing,
+                    extras,
+                });
+            }
+        }
+
+        Ok(Timed { parse, answers })
+    }
+
+    fn bench(&self, text: &str, options: &bench::Options) -> Result<Measurement, Error> {
+        let text = &input::normalize(text);
+        // parse and solve once up front, so that errors are reported instead of being measured
+        let input = self.parse(text).map_err(|e| e.in_day(S::DAY))?;
+        let in_part = |part| move |e: SolveError| e.in_part(part).in_day(S::DAY);
+        self.part1(&input).map_err(in_part(Part::One))?;
+        let has_part2 = self.part2(&input).map_err(in_part(Part::Two))?.is_some();
+        let parse = bench::measure(options, || self.parse(text));
+
+        let part1 = bench::measure(options, || self.part1(&input));
+        let part2 = has_part2.then(|| bench::measure(options, || self.part2(&input)));
+
+        Ok(Measurement {
+            parse,
+            part1,
+            part2,
+        })
+    }
+
+    fn analysis(&self, input: &str) -> Result<Option<String>, ParseError> {
+        let input = self
+            .parse(&input::normalize(input))
+            .map_err(|e| e.in_day(S::DAY))?;
+        Ok(Solution::analysis(self, &input))
+    }
+}
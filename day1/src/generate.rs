@@ -0,0 +1,33 @@
+//! Random calorie lists.
+
+use rand::Rng;
+
+/// Generates the food items of `size` elves, each carrying one to six items.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| format!("{}\n", rng.gen_range(1000..=60000)))
+                .collect()
+        })
+        .collect();
+
+    elves.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day1.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! The food items carried by the elves, and statistics about their calories.
+//!
+//! ```
+//! use day1::inventory::Inventory;
+//!
+//! let inventory = Inventory::new(vec![vec![1000, 2000], vec![4000], vec![500]]);
+//! assert_eq!(inventory.top_total(2), 7000);
+//! assert_eq!(inventory.rank(0), Some(2));
+//! assert_eq!(inventory.median(), Some(3000.0));
+//! ```
+
+use std::{cmp::Reverse, ops::RangeInclusive};
+
+/// The food items of a single elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    index: usize,
+    items: Vec<u32>,
+    total: u32,
+}
+
+impl Elf {
+    /// The position of the elf in the input, starting at 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The calories of each food item.
+    pub fn items(&self) -> &[u32] {
+        &self.items
+    }
+
+    /// The calories of all food items together.
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+/// The elves in the order of the input.
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    /// The indices of the elves, ordered by decreasing total (and by index for equal totals).
+    ranking: Vec<usize>,
+}
+
+impl Inventory {
+    /// Creates an inventory from the food items of each elf.
+    pub fn new(items: Vec<Vec<u32>>) -> Self {
+        let elves: Vec<Elf> = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Elf {
+                index,
+                total: items.iter().sum(),
+                items,
+            })
+            .collect();
+
+        let mut ranking: Vec<usize> = (0..elves.len()).collect();
+        ranking.sort_by_key(|&i| Reverse(elves[i].total));
+
+        Self { elves, ranking }
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Elf> {
+        self.elves.get(index)
+    }
+
+    /// The `k` elves carrying the most calories, starting with the one carrying the most.
+    pub fn top(&self, k: usize) -> impl Iterator<Item = &Elf> {
+        self.ranking.iter().take(k).map(|&i| &self.elves[i])
+    }
+
+    /// The calories carried by the `k` elves carrying the most calories.
+    pub fn top_total(&self, k: usize) -> u32 {
+        self.top(k).map(Elf::total).sum()
+    }
+
+    /// The rank of the elf with the given index, where the elf carrying the most calories has rank 1 and elves
+    /// carrying the same calories share a rank.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let total = self.get(index)?.total;
+        // the ranking is sorted by decreasing total, so the elves carrying more come first
+        let ahead = self
+            .ranking
+            .partition_point(|&i| self.elves[i].total > total);
+        Some(ahead + 1)
+    }
+
+    /// The median of the totals, which is the mean of the two middle totals for an even number of elves.
+    pub fn median(&self) -> Option<f64> {
+        let n = self.ranking.len();
+        if n == 0 {
+            return None;
+        }
+
+        let middle = |i: usize| self.elves[self.ranking[i]].total as f64;
+        Some((middle((n - 1) / 2) + middle(n / 2)) / 2.0)
+    }
+
+    /// The smallest total such that at least `p` percent of the elves carry at most this many calories (the
+    /// nearest-rank percentile), or `None` if there are no elves or `p` is not between 0 and 100.
+    pub fn percentile(&self, p: f64) -> Option<u32> {
+        let n = self.ranking.len();
+        if n == 0 || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+
+        // the ranking is in decreasing order, so the k-th smallest total is at n - k
+        // multiplying first keeps whole-number ranks exact, e.g. 7.0 / 100.0 * 100.0 is slightly above 7
+        let k = ((p * n as f64 / 100.0).ceil() as usize).max(1);
+        Some(self.elves[self.ranking[n - k]].total)
+    }
+
+    /// The elves whose total lies in `range`, in the order of the input.
+    pub fn within(&self, range: RangeInclusive<u32>) -> impl Iterator<Item = &Elf> {
+        self.elves
+            .iter()
+            .filter(move |elf| range.contains(&elf.total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inventory() -> Inventory {
+        Inventory::new(vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000],
+        ])
+    }
+
+    #[test]
+    fn top() {
+        let inventory = inventory();
+        let top: Vec<_> = inventory.top(3).map(Elf::index).collect();
+        assert_eq!(top, [3, 2, 4]);
+        assert_eq!(inventory.top_total(3), 45000);
+        assert_eq!(inventory.top_total(10), 55000);
+    }
+
+    #[test]
+    fn rank_with_ties() {
+        let inventory = Inventory::new(vec![vec![3], vec![5], vec![1, 2], vec![1]]);
+        assert_eq!(inventory.rank(1), Some(1));
+        assert_eq!(inventory.rank(0), Some(2));
+        assert_eq!(inventory.rank(2), Some(2));
+        assert_eq!(inventory.rank(3), Some(4));
+        assert_eq!(inventory.rank(4), None);
+
+        // the k-th elf of the ranking has rank k unless it ties with an elf before it
+        let top: Vec<_> = inventory
+            .top(4)
+            .map(|e| inventory.rank(e.index()))
+            .collect();
+        assert_eq!(top, [Some(1), Some(2), Some(2), Some(4)]);
+    }
+
+    #[test]
+    fn median_and_percentiles() {
+        let inventory = inventory();
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(40.0), Some(6000));
+        assert_eq!(inventory.percentile(41.0), Some(10000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(101.0), None);
+
+        let hundred = Inventory::new((1..=100).map(|total| vec![total]).collect());
+        assert_eq!(hundred.percentile(7.0), Some(7));
+        assert_eq!(hundred.percentile(7.5), Some(8));
+
+        let even = Inventory::new(vec![vec![1], vec![4], vec![2], vec![10]]);
+        assert_eq!(even.median(), Some(3.0));
+        assert_eq!(Inventory::default().median(), None);
+    }
+
+    #[test]
+    fn within() {
+        let inventory = inventory();
+        let elves: Vec<_> = inventory.within(5000..=11000).map(Elf::index).collect();
+        assert_eq!(elves, [0, 2, 4]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Day 1: Calorie Counting
+//!
+//! The input is parsed into an [`Inventory`] of the food items of each elf, see [`Inventory::top_total`].
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use inventory::Inventory;
+
+pub mod generate;
+pub mod inventory;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Inventory;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves = Vec::new();
+        let mut current: Option<Vec<u32>> = None;
+        for mut line in parse::lines(input) {
+            if line.is_empty() {
+                // an empty line separates the items of two elves
+                elves.extend(current.take());
+                continue;
+            }
+
+            let item = line.integer()?;
+            line.end()?;
+            current.get_or_insert_with(Vec::new).push(item);
+        }
+
+        elves.extend(current);
+        Ok(Inventory::new(elves))
+    }
+
+    fn part1(&self, inventory: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(inventory.top_total(1).into())
+    }
+
+    fn part2(&self, inventory: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(inventory.top_total(3).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn part1() {
+        let input = Day1.parse(SAMPLE).unwrap();
+        assert_eq!(Day1.part1(&input).unwrap(), 24000.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day1.parse(SAMPLE).unwrap();
+        assert_eq!(Day1.part2(&input).unwrap(), Some(45000.into()));
+    }
+
+    #[test]
+    fn elves_keep_their_items() {
+        let inventory = Day1.parse(SAMPLE).unwrap();
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(inventory.get(2).unwrap().items(), [5000, 6000]);
+        assert_eq!(inventory.rank(2), Some(2));
+    }
+
+    #[test]
+    fn malformed_line() {
+        let error = Day1.parse("1000\n\n20x0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+}
@@ -0,0 +1,44 @@
+//! Random CPU programs.
+
+use rand::Rng;
+
+/// Generates a program of `size` instructions, extended until it runs for at least 240 cycles to draw a full screen.
+///
+/// The X register stays between -10 and 50, so that the sprite is never far off the screen.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut output = String::new();
+    let mut x = 1;
+    let mut cycles = 0;
+    let mut count = 0;
+    while count < size || cycles < 240 {
+        if rng.gen_bool(0.3) {
+            output.push_str("noop\n");
+            cycles += 1;
+        } else {
+            let value = rng.gen_range((-10 - x).max(-20)..=(50 - x).min(20));
+            output.push_str(&format!("addx {value}\n"));
+            x += value;
+            cycles += 2;
+        }
+        count += 1;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day10;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day10.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
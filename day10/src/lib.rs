@@ -0,0 +1,314 @@
+//! Day 10: Cathode-Ray Tube
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use visualize::Frame;
+
+pub mod generate;
+
+/// The CPU and the screen it draws on.
+pub struct State {
+    cycle: u32,
+    xreg: i32,
+    signal_strength: i32,
+    screen: String,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self {
+            cycle: 1,
+            xreg: 1,
+            signal_strength: 0,
+            screen: String::new(),
+        }
+    }
+
+    pub fn noop(&mut self) {
+        self.next_cycle();
+    }
+
+    pub fn addx(&mut self, val: i32) {
+        self.next_cycle();
+        self.next_cycle();
+        self.xreg += val;
+    }
+
+    /// The sum of the signal strengths during the 20th, 60th, 100th, ... cycle.
+    pub fn signal_strength(&self) -> i32 {
+        // assume that if the last instruction ended right before a critical cycle, this next cycle is not executed and
+        // thus does not contribute to the signal strength (in particular, if the last instruction was addx, the new X
+        // value does not matter)
+        self.signal_strength
+    }
+
+    /// The pixels drawn so far, as rows of 40 `#` (lit) and `.` (dark) pixels.
+    pub fn screen(&self) -> &str {
+        &self.screen
+    }
+
+    fn next_cycle(&mut self) {
+        if self.cycle > 1 && self.cycle % 40 == 1 {
+            self.screen.push('\n');
+        }
+
+        if ((self.cycle as i32 - 1) % 40 - self.xreg).abs() <= 1 {
+            self.screen.push('#');
+        } else {
+            self.screen.push('.');
+        }
+
+        visualize::emit(|| {
+            let rows: Vec<&[u8]> = self.screen.lines().map(str::as_bytes).collect();
+            Frame::from_fn(40, 6, |x, y| {
+                rows.get(y)
+                    .and_then(|row| row.get(x))
+                    .map_or(' ', |&b| b as char)
+            })
+        });
+
+        if (self.cycle + 20).is_multiple_of(40) {
+            self.signal_strength += self.cycle as i32 * self.xreg;
+        }
+
+        self.cycle += 1;
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+/// Runs the program and returns the state after its last instruction.
+pub fn run(program: &[Instruction]) -> State {
+    let mut state = State::new();
+    for instruction in program {
+        match *instruction {
+            Instruction::Noop => state.noop(),
+            Instruction::Addx(val) => state.addx(val),
+        }
+    }
+
+    state
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|mut line| {
+                let instruction = if line.eat("noop") {
+                    Instruction::Noop
+                } else if line.eat("addx ") {
+                    Instruction::Addx(line.integer()?)
+                } else {
+                    return Err(line.error("\"noop\" or \"addx\""));
+                };
+                line.end()?;
+
+                Ok(instruction)
+            })
+            .collect()
+    }
+
+    fn part1(&self, program: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(run(program).signal_strength().into())
+    }
+
+    fn part2(&self, program: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(run(program).screen().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+    #[test]
+    fn part1() {
+        let input = Day10.parse(SAMPLE).unwrap();
+        assert_eq!(Day10.part1(&input).unwrap(), 13140.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day10.parse(SAMPLE).unwrap();
+        assert_eq!(
+            Day10.part2(&input).unwrap(),
+            Some(Answer::Text(
+                "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+                    .into()
+            ))
+        );
+    }
+}
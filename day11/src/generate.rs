@@ -0,0 +1,147 @@
+//! Random monkey notes in the format of `monkeys.lalrpop`.
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::state::{Level, Monkey, Operand, Operation, Operator, Test};
+
+const DIVISORS: [Level; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Generates the notes of `size` monkeys (between 2 and 15), each testing for a different prime.
+///
+/// Monkeys are drawn until the worry levels of part 1 fit into a [`Level`].
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let count = size.clamp(2, DIVISORS.len());
+    let monkeys = loop {
+        let monkeys = random_monkeys(rng, count);
+        if fits_part1(monkeys.clone()) {
+            break monkeys;
+        }
+    };
+
+    let notes: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, m)| note(i, m))
+        .collect();
+    notes.join("\n")
+}
+
+fn random_monkeys(rng: &mut impl Rng, count: usize) -> Vec<Monkey> {
+    let mut divisors = DIVISORS[..count].to_vec();
+    divisors.shuffle(rng);
+    let squaring = rng.gen_range(0..count);
+
+    (0..count)
+        .map(|i| {
+            let items = (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range(50..100))
+                .collect();
+
+            let (op, rhs) = if i == squaring {
+                (Operator::Multiply, Operand::Old)
+            } else if rng.gen() {
+                (Operator::Add, Operand::Level(rng.gen_range(1..=9)))
+            } else {
+                (Operator::Multiply, Operand::Level(rng.gen_range(2..=19)))
+            };
+
+            let mut others: Vec<usize> = (0..count).filter(|&j| j != i).collect();
+            others.shuffle(rng);
+            Monkey {
+                items,
+                operation: Operation {
+                    lhs: Operand::Old,
+                    op,
+                    rhs,
+                },
+                test: Test {
+                    divisor: divisors[i],
+                    if_true: others[0],
+                    if_false: *others.get(1).unwrap_or(&others[0]),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Plays the 20 rounds of part 1 with overflow checks.
+fn fits_part1(mut monkeys: Vec<Monkey>) -> bool {
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let Monkey {
+                items,
+                operation,
+                test,
+            } = &mut monkeys[i];
+            let mut thrown = Vec::new();
+            for item in items.drain(..) {
+                let operand = |operand: &Operand| match *operand {
+                    Operand::Old => item,
+                    Operand::Level(n) => n,
+                };
+                let (lhs, rhs) = (operand(&operation.lhs), operand(&operation.rhs));
+                let level = match operation.op {
+                    Operator::Add => lhs.checked_add(rhs),
+                    Operator::Multiply => lhs.checked_mul(rhs),
+                };
+                let Some(level) = level else {
+                    return false;
+                };
+
+                let level = level / 3;
+                thrown.push((test.test(level), level));
+            }
+
+            for (target, level) in thrown {
+                monkeys[target].items.push(level);
+            }
+        }
+    }
+
+    true
+}
+
+fn note(index: usize, monkey: &Monkey) -> String {
+    let items: Vec<String> = monkey.items.iter().map(Level::to_string).collect();
+    let operand = |operand: &Operand| match operand {
+        Operand::Old => "old".to_owned(),
+        Operand::Level(n) => n.to_string(),
+    };
+    let op = match monkey.operation.op {
+        Operator::Add => '+',
+        Operator::Multiply => '*',
+    };
+
+    format!(
+        "Monkey {index}:
+  Starting items: {}
+  Operation: new = {} {op} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+",
+        items.join(", "),
+        operand(&monkey.operation.lhs),
+        operand(&monkey.operation.rhs),
+        monkey.test.divisor,
+        monkey.test.if_true,
+        monkey.test.if_false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day11;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 8);
+            Day11.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Day 11: Monkey in the Middle
+//!
+//! The worry levels of part 2 grow too large for any integer type, so [`ModuloMonkey`] only keeps their residues
+//! modulo the divisors of all monkeys' tests, which is enough to decide every test.
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use itertools::{self, Itertools};
+use lalrpop_util::lalrpop_mod;
+use std::{cmp::Reverse, fmt::Display};
+
+use monkeys::MonkeysParser;
+pub use state::{ModuloMonkey, Monkey};
+
+pub mod generate;
+pub mod state;
+lalrpop_mod!(
+    #[allow(clippy::all)]
+    monkeys
+);
+
+/// Converts an error of the generated parser into a [`ParseError`] pointing to the same position in `input`.
+fn convert_error<T: Display>(
+    input: &str,
+    error: lalrpop_util::ParseError<usize, T, &str>,
+) -> ParseError {
+    use lalrpop_util::ParseError::*;
+
+    let error_at = |offset, expected: &[String]| {
+        let (line, column) = parse::location(input, offset);
+        ParseError::new(line, column, expected.join(" or "))
+    };
+
+    match error {
+        InvalidToken { location } => {
+            let c = input[location..].chars().next().unwrap_or_default();
+            error_at(location, &["a valid token".to_owned()]).found(format!("{c:?}"))
+        }
+        UnrecognizedEOF { location, expected } => {
+            error_at(location, &expected).found("end of input")
+        }
+        UnrecognizedToken {
+            token: (start, token, _),
+            expected,
+        } => error_at(start, &expected).found(format!("\"{token}\"")),
+        ExtraToken {
+            token: (start, token, _),
+        } => error_at(start, &["end of input".to_owned()]).found(format!("\"{token}\"")),
+        User { error } => ParseError::new(1, 1, error),
+    }
+}
+
+/// The product of the two highest numbers of inspected items after 20 rounds in which the worry level is divided by
+/// three after each inspection.
+pub fn run1(mut monkeys: Vec<Monkey>) -> u32 {
+    let mut inspected = vec![0; monkeys.len()];
+
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let actions = monkeys[i].turn();
+            inspected[i] += actions.len() as u32;
+            for act in actions {
+                monkeys[act.target].items.push(act.item);
+            }
+        }
+    }
+
+    let result: u32 = inspected
+        .iter()
+        .map(Reverse)
+        .k_smallest(2)
+        .map(|r| r.0)
+        .product();
+
+    result
+}
+
+/// The product of the two highest numbers of inspected items after 10000 rounds without relief.
+pub fn run2(mut monkeys: Vec<ModuloMonkey>) -> u64 {
+    let mut inspected = vec![0; monkeys.len()];
+    let len = monkeys.len();
+
+    for _ in 0..10000 {
+        for i in 0..len {
+            let actions = monkeys[i].turn();
+            inspected[i] += actions.len() as u32;
+            for act in actions {
+                monkeys[act.target].items.push(act.item);
+            }
+        }
+    }
+
+    let result: u64 = inspected
+        .iter()
+        .map(Reverse)
+        .k_smallest(2)
+        .map(|r| *r.0 as u64)
+        .product();
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        MonkeysParser::new()
+            .parse(input)
+            .map_err(|e| convert_error(input, e))
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(run1(monkeys.clone()).into())
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let moduli = monkeys.iter().map(|m| m.test.divisor).collect_vec();
+        let modulo_monkey = monkeys
+            .iter()
+            .cloned()
+            .map(|m| ModuloMonkey::new(m, &moduli))
+            .collect();
+
+        Ok(Some(run2(modulo_monkey).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn part1() {
+        let input = Day11.parse(SAMPLE).unwrap();
+        assert_eq!(Day11.part1(&input).unwrap(), 10605.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day11.parse(SAMPLE).unwrap();
+        assert_eq!(Day11.part2(&input).unwrap(), Some(2713310158u64.into()));
+    }
+}
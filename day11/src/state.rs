@@ -1,5 +1,8 @@
+//! The monkeys and the worry levels of their items.
+
 use itertools::Itertools;
 
+/// The worry level of an item.
 pub type Level = u32;
 
 #[derive(Debug, Clone)]
@@ -14,6 +17,7 @@ pub enum Operator {
     Multiply,
 }
 
+/// How a monkey changes the worry level of an item when inspecting it.
 #[derive(Debug, Clone)]
 pub struct Operation {
     pub lhs: Operand,
@@ -45,6 +49,7 @@ impl Operation {
     }
 }
 
+/// Decides which monkey an item is thrown to.
 #[derive(Debug, Clone)]
 pub struct Test {
     pub divisor: Level,
@@ -53,8 +58,9 @@ pub struct Test {
 }
 
 impl Test {
+    /// The index of the monkey that receives an item with the worry level `input`.
     pub fn test(&self, input: Level) -> usize {
-        if input % self.divisor == 0 {
+        if input.is_multiple_of(self.divisor) {
             self.if_true
         } else {
             self.if_false
@@ -62,6 +68,7 @@ impl Test {
     }
 }
 
+/// An item thrown to the monkey at index `target`.
 #[derive(Debug)]
 pub struct Action<T> {
     pub item: T,
@@ -76,6 +83,7 @@ pub struct Monkey {
 }
 
 impl Monkey {
+    /// Inspects and throws all items, dividing their worry levels by three.
     pub fn turn(&mut self) -> Vec<Action<Level>> {
         self.items
             .drain(..)
@@ -89,6 +97,7 @@ impl Monkey {
     }
 }
 
+/// A worry level represented by its residues modulo a list of moduli.
 #[derive(Debug, Clone)]
 pub struct ModuloLevel<'a> {
     residues: Vec<Level>,
@@ -103,6 +112,12 @@ impl<'a> ModuloLevel<'a> {
         }
     }
 
+    /// The residues of the level, in the order of the moduli.
+    pub fn residues(&self) -> &[Level] {
+        &self.residues
+    }
+
+    /// Applies the operation to the level, keeping only the residues.
     pub fn eval_mut(&mut self, op: &Operation) {
         for i in 0..self.residues.len() {
             self.residues[i] = op.eval(self.residues[i]) % self.moduli[i];
@@ -110,6 +125,7 @@ impl<'a> ModuloLevel<'a> {
     }
 }
 
+/// A monkey whose items are [`ModuloLevel`]s, for rounds without relief.
 #[derive(Debug, Clone)]
 pub struct ModuloMonkey<'a> {
     pub items: Vec<ModuloLevel<'a>>,
@@ -119,6 +135,7 @@ pub struct ModuloMonkey<'a> {
 }
 
 impl<'a> ModuloMonkey<'a> {
+    /// Converts a monkey, whose test divisor must be one of `moduli`.
     pub fn new(monkey: Monkey, moduli: &'a [u32]) -> Self {
         let Monkey {
             items,
@@ -0,0 +1,131 @@
+//! Compares the residue arithmetic of part 2 against plain `u128` worry levels.
+
+use day11::{
+    run2,
+    state::{ModuloLevel, Operand, Operation, Operator, Test},
+    ModuloMonkey, Monkey,
+};
+use itertools::Itertools;
+use proptest::prelude::*;
+
+const PRIMES: [u32; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+fn operation() -> impl Strategy<Value = Operation> {
+    let op = prop_oneof![Just(Operator::Add), Just(Operator::Multiply)];
+    let rhs = prop_oneof![Just(Operand::Old), (0..20u32).prop_map(Operand::Level)];
+    (op, rhs).prop_map(|(op, rhs)| Operation {
+        lhs: Operand::Old,
+        op,
+        rhs,
+    })
+}
+
+fn eval(operation: &Operation, old: u128) -> Option<u128> {
+    let operand = |operand: &Operand| match operand {
+        Operand::Old => old,
+        &Operand::Level(n) => n.into(),
+    };
+    let (lhs, rhs) = (operand(&operation.lhs), operand(&operation.rhs));
+    match operation.op {
+        Operator::Add => lhs.checked_add(rhs),
+        Operator::Multiply => lhs.checked_mul(rhs),
+    }
+}
+
+/// Monkeys with distinct prime divisors that never throw items to themselves.
+fn monkeys() -> impl Strategy<Value = Vec<Monkey>> {
+    (2..=5usize).prop_flat_map(|n| {
+        let monkey = (
+            prop::collection::vec(0..100u32, 0..5),
+            operation(),
+            1..n,
+            1..n,
+        );
+        (
+            Just(PRIMES).prop_shuffle(),
+            prop::collection::vec(monkey, n),
+        )
+            .prop_map(move |(divisors, monkeys)| {
+                monkeys
+                    .into_iter()
+                    .zip(divisors)
+                    .enumerate()
+                    .map(|(i, ((items, operation, t, f), divisor))| Monkey {
+                        items,
+                        operation,
+                        test: Test {
+                            divisor,
+                            if_true: (i + t) % n,
+                            if_false: (i + f) % n,
+                        },
+                    })
+                    .collect()
+            })
+    })
+}
+
+/// Plays 10000 rounds without relief, keeping the worry levels modulo the product of all divisors.
+fn monkey_business(monkeys: &[Monkey]) -> u64 {
+    let modulus: u128 = monkeys.iter().map(|m| u128::from(m.test.divisor)).product();
+    let mut items: Vec<Vec<u128>> = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|&i| i.into()).collect())
+        .collect();
+    let mut inspected = vec![0u64; monkeys.len()];
+
+    for _ in 0..10000 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspected[i] += 1;
+                let level = eval(&monkey.operation, item).unwrap() % modulus;
+                let target = if level.is_multiple_of(monkey.test.divisor.into()) {
+                    monkey.test.if_true
+                } else {
+                    monkey.test.if_false
+                };
+                items[target].push(level);
+            }
+        }
+    }
+
+    inspected.iter().sorted().rev().take(2).product()
+}
+
+proptest! {
+    #[test]
+    fn residues_match_exact_levels(
+        level in 0..100u32,
+        moduli in prop::collection::vec(2..30u32, 1..5),
+        operations in prop::collection::vec(operation(), 0..20),
+    ) {
+        let mut modulo = ModuloLevel::new(level, &moduli);
+        let mut exact = u128::from(level);
+        for operation in &operations {
+            // stop as soon as the exact level no longer fits
+            let Some(next) = eval(operation, exact) else {
+                break;
+            };
+
+            exact = next;
+            modulo.eval_mut(operation);
+            let expected = moduli.iter().map(|&m| (exact % u128::from(m)) as u32).collect_vec();
+            prop_assert_eq!(modulo.residues(), &expected[..]);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn run2_matches_reduced_levels(monkeys in monkeys()) {
+        let moduli = monkeys.iter().map(|m| m.test.divisor).collect_vec();
+        let modulo_monkeys = monkeys
+            .iter()
+            .cloned()
+            .map(|m| ModuloMonkey::new(m, &moduli))
+            .collect();
+
+        prop_assert_eq!(run2(modulo_monkeys), monkey_business(&monkeys));
+    }
+}
@@ -0,0 +1,54 @@
+//! Random heightmaps.
+
+use rand::Rng;
+
+/// Generates a heightmap that is `size` columns (at least 26) wide and a third as high.
+///
+/// The height rises from `a` in the first column to `z` in the last column, with random dips everywhere except on
+/// one row, which leads from the first column to the best signal in the last column. The start is in the first
+/// column, which only contains `a`.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 3).max(5);
+    let path_row = rng.gen_range(0..height);
+    let start_row = rng.gen_range(0..height);
+
+    let mut output = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let base = (x * 25 / (width - 1)) as u8;
+            let level = if y == path_row {
+                base
+            } else {
+                base.saturating_sub(rng.gen_range(0..=3))
+            };
+
+            let c = match (x, y) {
+                (0, y) if y == start_row => 'S',
+                (x, y) if x == width - 1 && y == path_row => 'E',
+                _ => (b'a' + level) as char,
+            };
+            output.push(c);
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+            Day12.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Day 12: Hill Climbing Algorithm
+
+use common::{parse::ParseError, Answer, Solution, SolveError};
+use grid::{DenseGrid, Grid, Point};
+
+pub mod generate;
+
+fn valid_step(start: u8, end: u8) -> bool {
+    end <= start + 1
+}
+
+/// The fewest steps from any of `starts` to `end`, climbing at most one level per step.
+pub fn fewest_steps(
+    grid: &DenseGrid<u8>,
+    starts: impl IntoIterator<Item = Point>,
+    end: Point,
+) -> Option<u32> {
+    let neighbors = |&pos: &Point| {
+        grid.neighbors4(pos)
+            .filter(move |&next| valid_step(grid[pos], grid[next]))
+    };
+
+    search::bfs(starts, neighbors, |&pos| pos == end).cost()
+}
+
+/// The heights of the area (`a` to `z`), with the start and end positions replaced by their heights.
+pub struct Heightmap {
+    grid: DenseGrid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl Heightmap {
+    pub fn grid(&self) -> &DenseGrid<u8> {
+        &self.grid
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Heightmap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut start = None;
+        let mut end = None;
+
+        let grid = DenseGrid::parse(input, |p, level| match level {
+            b'S' => {
+                start = Some(p);
+                Ok(b'a')
+            }
+            b'E' => {
+                end = Some(p);
+                Ok(b'z')
+            }
+            b'a'..=b'z' => Ok(level),
+            _ => Err(grid::unexpected_cell(
+                p,
+                level,
+                "an elevation (a-z, S or E)",
+            )),
+        })?;
+
+        let missing = |what| ParseError::end_of_input(grid.height() as usize + 1, what);
+        Ok(Heightmap {
+            start: start.ok_or_else(|| missing("the start position \"S\""))?,
+            end: end.ok_or_else(|| missing("the best signal position \"E\""))?,
+            grid,
+        })
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(fewest_steps(&map.grid, [map.start], map.end)
+            .expect("no path found")
+            .into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let starts = map
+            .grid
+            .iter()
+            .filter(|&(_, &level)| level == b'a')
+            .map(|(p, _)| p);
+        let steps = fewest_steps(&map.grid, starts, map.end).expect("no path found");
+        Ok(Some(steps.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1() {
+        let input = Day12.parse(SAMPLE).unwrap();
+        assert_eq!(Day12.part1(&input).unwrap(), 31.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day12.parse(SAMPLE).unwrap();
+        assert_eq!(Day12.part2(&input).unwrap(), Some(29.into()));
+    }
+}
@@ -0,0 +1,43 @@
+//! Random pairs of packets.
+
+use rand::Rng;
+
+/// Generates `size` pairs of packets.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0)))
+        .collect();
+
+    pairs.join("\n")
+}
+
+fn list(rng: &mut impl Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) {
+                list(rng, depth + 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect();
+
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day13;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day13.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
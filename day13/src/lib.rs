@@ -0,0 +1,161 @@
+//! Day 13: Distress Signal
+
+use std::cmp::Ordering;
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// A packet, which is ordered by the rules of the distress signal.
+///
+/// ```
+/// use day13::Packet::{Integer, List};
+///
+/// assert!(List(vec![Integer(2)]) < List(vec![Integer(3), Integer(1)]));
+/// assert!(Integer(4) > List(vec![List(vec![Integer(3)])]));
+/// ```
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Integer(i32),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => a.cmp(b),
+            (Self::List(a), Self::Integer(b)) => (**a).cmp(&[Self::Integer(*b)]),
+            (a, b) => b.cmp(a).reverse(),
+        }
+    }
+}
+
+impl Packet {
+    /// Parses a packet like `[1,[2,3]]` at the start of `line`.
+    pub fn parse(line: &mut Line) -> Result<Packet, ParseError> {
+        if line.eat("[") {
+            let mut items = vec![];
+            if !line.eat("]") {
+                loop {
+                    items.push(Packet::parse(line)?);
+                    if line.eat("]") {
+                        break;
+                    }
+
+                    line.expect(",")?;
+                }
+            }
+
+            Ok(Self::List(items))
+        } else {
+            Ok(Self::Integer(line.integer()?))
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Packet>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .filter(|line| !line.is_empty())
+            .map(|mut line| {
+                let packet = Packet::parse(&mut line)?;
+                line.end()?;
+                Ok(packet)
+            })
+            .collect()
+    }
+
+    fn part1(&self, packets: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for (idx, pair) in packets.chunks_exact(2).enumerate() {
+            if pair[0] <= pair[1] {
+                sum += idx + 1;
+            }
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, packets: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Integer(n)])]);
+        let divider1 = divider(2);
+        let divider2 = divider(6);
+        let mut packets = packets.clone();
+        packets.push(divider1.clone());
+        packets.push(divider2.clone());
+
+        packets.sort();
+
+        let idx1 = packets.iter().position(|p| p == &divider1).unwrap_or(0) + 1;
+        let idx2 = packets.iter().position(|p| p == &divider2).unwrap_or(0) + 1;
+
+        Ok(Some((idx1 * idx2).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn part1() {
+        let input = Day13.parse(SAMPLE).unwrap();
+        assert_eq!(Day13.part1(&input).unwrap(), 13.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day13.parse(SAMPLE).unwrap();
+        assert_eq!(Day13.part2(&input).unwrap(), Some(140.into()));
+    }
+}
@@ -0,0 +1,48 @@
+//! Random rock scans.
+
+use rand::Rng;
+
+/// Generates `size` rock paths of horizontal and vertical lines below the source of the sand.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let spread = size as i32 + 10;
+    let depth = size as i32 / 2 + 10;
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut x = rng.gen_range(500 - spread..=500 + spread);
+            let mut y = rng.gen_range(2..=depth);
+            let mut points = vec![format!("{x},{y}")];
+            let mut horizontal = rng.gen();
+            for _ in 0..rng.gen_range(1..=4) {
+                let length = rng.gen_range(1..=8) * if rng.gen() { 1 } else { -1 };
+                if horizontal {
+                    x += length;
+                } else {
+                    // stay below the source, so that the sand can always enter
+                    y = (y + length).max(2);
+                }
+                horizontal = !horizontal;
+                points.push(format!("{x},{y}"));
+            }
+
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day14;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day14.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Day 14: Regolith Reservoir
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use grid::{Bounds, Grid, Point, SparseGrid};
+use visualize::Frame;
+
+pub mod generate;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+const SOURCE: Point = Point::new(500, 0);
+
+fn parse_point(line: &mut Line) -> Result<Point, ParseError> {
+    let x = line.integer()?;
+    line.expect(",")?;
+    let y = line.integer()?;
+    Ok(Point::new(x, y))
+}
+
+/// Returns the position where the sand comes to rest, or the position where it falls below the lowest rock.
+fn simulate_drop(map: &SparseGrid<Tile>, y_max: i32) -> Result<Point, Point> {
+    let mut pos = SOURCE;
+    loop {
+        if pos.y > y_max {
+            return Err(pos);
+        }
+
+        let next = [(0, 1), (-1, 1), (1, 1)]
+            .into_iter()
+            .map(|offset| pos + offset.into())
+            .find(|&p| !map.contains(p));
+
+        match next {
+            Some(p) => pos = p,
+            None => return Ok(pos),
+        }
+    }
+}
+
+/// Renders the rocks ('#'), the sand ('o') and the source of the sand ('+').
+fn render(map: &SparseGrid<Tile>) -> Frame {
+    let bounds = Bounds::enclosing(map.points().chain([SOURCE])).unwrap();
+    Frame::from_fn(bounds.width() as usize, bounds.height() as usize, |x, y| {
+        let p = bounds.min + Point::new(x as i32, y as i32);
+        match map.get(p) {
+            Some(Tile::Rock) => '#',
+            Some(Tile::Sand) => 'o',
+            None if p == SOURCE => '+',
+            None => '.',
+        }
+    })
+}
+
+/// The scanned rocks of the cave.
+pub struct Scan {
+    map: SparseGrid<Tile>,
+    y_max: i32,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Scan;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut map = SparseGrid::new();
+        let mut y_max = 0;
+        for mut line in parse::lines(input) {
+            let mut p1 = parse_point(&mut line)?;
+            while !line.is_empty() {
+                line.expect(" -> ")?;
+                let column = line.column();
+                let p2 = parse_point(&mut line)?;
+
+                y_max = y_max.max(p1.y).max(p2.y);
+
+                if p1.x == p2.x {
+                    // vertical line
+                    for y in p1.y.min(p2.y)..=p1.y.max(p2.y) {
+                        map.insert(Point::new(p1.x, y), Tile::Rock);
+                    }
+                } else if p1.y == p2.y {
+                    // horizontal line
+                    for x in p1.x.min(p2.x)..=p1.x.max(p2.x) {
+                        map.insert(Point::new(x, p1.y), Tile::Rock);
+                    }
+                } else {
+                    return Err(ParseError::new(
+                        line.number(),
+                        column,
+                        format!("a point in line with {},{}", p1.x, p1.y),
+                    )
+                    .found(format!("{},{}", p2.x, p2.y)));
+                }
+
+                p1 = p2;
+            }
+        }
+
+        Ok(Scan { map, y_max })
+    }
+
+    fn part1(&self, scan: &Self::Input) -> Result<Answer, SolveError> {
+        let mut map = scan.map.clone();
+        let mut counter = 0;
+        // the sand can also pile up to the source if the rocks form a basin around it
+        while !map.contains(SOURCE) {
+            let Ok(p) = simulate_drop(&map, scan.y_max) else {
+                break;
+            };
+            map.insert(p, Tile::Sand);
+            counter += 1;
+            visualize::emit(|| render(&map));
+        }
+
+        Ok(counter.into())
+    }
+
+    fn part2(&self, scan: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let mut map = scan.map.clone();
+        let mut counter = 0;
+        while !map.contains(SOURCE) {
+            // sand falling below the lowest rock comes to rest on the floor
+            let (Ok(p) | Err(p)) = simulate_drop(&map, scan.y_max);
+            map.insert(p, Tile::Sand);
+            counter += 1;
+
+            // tens of thousands of units of sand are needed to fill the cave, so only show every 100th one
+            if counter % 100 == 0 || map.contains(SOURCE) {
+                visualize::emit(|| render(&map));
+            }
+        }
+
+        Ok(Some(counter.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+    #[test]
+    fn part1() {
+        let input = Day14.parse(SAMPLE).unwrap();
+        assert_eq!(Day14.part1(&input).unwrap(), 24.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day14.parse(SAMPLE).unwrap();
+        assert_eq!(Day14.part2(&input).unwrap(), Some(93.into()));
+    }
+
+    #[test]
+    fn diagonal_line() {
+        let error = Day14.parse("498,4 -> 496,6").err().unwrap();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 10);
+    }
+}
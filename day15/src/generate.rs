@@ -0,0 +1,73 @@
+//! Random sensor reports.
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{lines::Point, Day15};
+
+fn distance(p1: Point, p2: Point) -> i32 {
+    (p1.0 - p2.0).abs() + (p1.1 - p2.1).abs()
+}
+
+/// Generates the reports of `size` sensors in addition to four sensors that cover the whole square of the default
+/// [`Day15`] except for a single hidden point.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let square = Day15::default().size;
+    let hidden = (rng.gen_range(0..=square), rng.gen_range(0..=square));
+
+    // four sensors diagonally around the hidden point, whose ranges end right before it, cover everything within
+    // `reach` of the point (and thus the square)
+    let reach = square + 1 + rng.gen_range(0..1000);
+    let mut reports: Vec<(Point, Point)> = [
+        (1, 1, (1, 0)),
+        (-1, 1, (0, 1)),
+        (-1, -1, (-1, 0)),
+        (1, -1, (0, -1)),
+    ]
+    .into_iter()
+    .map(|(dx, dy, beacon)| {
+        let sensor = (hidden.0 + dx * reach, hidden.1 + dy * reach);
+        (sensor, (hidden.0 + beacon.0, hidden.1 + beacon.1))
+    })
+    .collect();
+
+    // other sensors anywhere in the square, whose ranges do not reach the hidden point
+    while reports.len() < size + 4 {
+        let sensor = (rng.gen_range(0..=square), rng.gen_range(0..=square));
+        let max_range = distance(sensor, hidden) - 1;
+        if max_range < 0 {
+            continue;
+        }
+
+        let range = rng.gen_range(0..=max_range);
+        let dx = rng.gen_range(-range..=range);
+        let dy = (range - dx.abs()) * if rng.gen() { 1 } else { -1 };
+        reports.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+
+    reports.shuffle(rng);
+    reports
+        .into_iter()
+        .map(|(s, b)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.0, s.1, b.0, b.1
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day15::default().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -1,4 +1,20 @@
-#[derive(Debug)]
+//! Sets of integers built from intervals.
+//!
+//! ```
+//! use day15::intervals::{Interval, IntervalSet};
+//!
+//! let mut set = IntervalSet::new();
+//! set.insert(Interval::new(-2, 4));
+//! set.insert(Interval::new(3, 8));
+//! set.insert(Interval::empty());
+//! assert_eq!(set.area(), 11);
+//! assert_eq!(set.first_missing(-5, 20), Some(-5));
+//! assert_eq!(set.first_missing(-2, 20), Some(9));
+//! assert_eq!(set.first_missing(-2, 8), None);
+//! ```
+
+/// An inclusive range of integers, which is empty if `from > to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Interval {
     from: i32,
     to: i32,
@@ -9,10 +25,12 @@ impl Interval {
         Self { from, to }
     }
 
+    /// An interval without any integers.
     pub fn empty() -> Self {
         Self { from: 1, to: 0 }
     }
 
+    /// The parts of this interval that are not part of `other`.
     fn clip(self, other: &Self) -> Vec<Self> {
         if self.from >= other.from && self.to <= other.to {
             // other encloses self, clip is empty
@@ -47,11 +65,12 @@ impl Interval {
         }
     }
 
-    fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.from > self.to
     }
 
-    fn area(&self) -> i32 {
+    /// The number of integers in the interval.
+    pub fn area(&self) -> i32 {
         if self.is_empty() {
             0
         } else {
@@ -60,6 +79,8 @@ impl Interval {
     }
 }
 
+/// A union of intervals, stored as disjoint intervals.
+#[derive(Debug, Default)]
 pub struct IntervalSet {
     areas: Vec<Interval>,
 }
@@ -69,6 +90,7 @@ impl IntervalSet {
         Self { areas: Vec::new() }
     }
 
+    /// Adds the integers of `interval` to the set.
     pub fn insert(&mut self, interval: Interval) {
         let new_areas = self
             .areas
@@ -82,7 +104,21 @@ impl IntervalSet {
         self.areas.extend(new_areas);
     }
 
+    /// The number of integers in the set.
     pub fn area(&self) -> i32 {
         self.areas.iter().map(Interval::area).sum()
     }
+
+    /// The smallest integer from `from` to `to` that is not in the set.
+    pub fn first_missing(&self, from: i32, to: i32) -> Option<i32> {
+        let mut x = from;
+        while x <= to {
+            match self.areas.iter().find(|a| a.from <= x && x <= a.to) {
+                Some(area) => x = area.to + 1,
+                None => return Some(x),
+            }
+        }
+
+        None
+    }
 }
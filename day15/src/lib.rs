@@ -0,0 +1,224 @@
+//! Day 15: Beacon Exclusion Zone
+//!
+//! The covered positions of a row are collected into an [`IntervalSet`], and the distress beacon is found by
+//! [`find_uncovered_point`] among the intersections of the [`lines`] just outside the range of each [`Sensor`].
+
+use std::collections::HashSet;
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use intervals::{Interval, IntervalSet};
+use itertools::Itertools;
+use lines::{Line, Point};
+
+pub mod generate;
+pub mod intervals;
+pub mod lines;
+
+/// A sensor together with the closest beacon it detected.
+#[derive(Debug, Clone)]
+pub struct Sensor {
+    pos: Point,
+    range: i32,
+    beacon: Point,
+}
+
+impl Sensor {
+    fn parse(mut line: parse::Line) -> Result<Self, ParseError> {
+        line.expect("Sensor at x=")?;
+        let x: i32 = line.integer()?;
+        line.expect(", y=")?;
+        let y: i32 = line.integer()?;
+        line.expect(": closest beacon is at x=")?;
+        let beacon_x: i32 = line.integer()?;
+        line.expect(", y=")?;
+        let beacon_y: i32 = line.integer()?;
+        line.end()?;
+
+        Ok(Self::new((x, y), (beacon_x, beacon_y)))
+    }
+
+    pub fn new(pos: Point, beacon: Point) -> Self {
+        Self {
+            pos,
+            range: (pos.0 - beacon.0).abs() + (pos.1 - beacon.1).abs(),
+            beacon,
+        }
+    }
+
+    pub fn pos(&self) -> Point {
+        self.pos
+    }
+
+    pub fn beacon(&self) -> Point {
+        self.beacon
+    }
+
+    /// The positions in row `y` that are covered by the sensor, i.e. where no other beacon can be.
+    pub fn y_interval(&self, y: i32) -> Interval {
+        let diff = (y - self.pos.1).abs();
+        if diff <= self.range {
+            Interval::new(
+                self.pos.0 - self.range + diff,
+                self.pos.0 + self.range - diff,
+            )
+        } else {
+            Interval::empty()
+        }
+    }
+
+    fn exterior(&self) -> [Line; 4] {
+        // each corner belongs to both of its lines, because the uncovered point may be the intersection of a line of
+        // another sensor with either of them
+        let (x, y) = self.pos;
+        let d = self.range + 1;
+        [
+            Line::new((x, y - d), (x + d, y)),
+            Line::new((x, y - d), (x - d, y)),
+            Line::new((x - d, y), (x, y + d)),
+            Line::new((x + d, y), (x, y + d)),
+        ]
+    }
+
+    /// Whether `p` is at most as far from the sensor as its closest beacon.
+    pub fn covers(&self, p: Point) -> bool {
+        self.distance(p) <= self.range
+    }
+
+    fn distance(&self, p: Point) -> i32 {
+        (p.0 - self.pos.0).abs() + (p.1 - self.pos.1).abs()
+    }
+}
+
+/// Finds a point with coordinates from 0 to `size` that is not covered by any sensor.
+///
+/// The search relies on there being at most one such point.
+pub fn find_uncovered_point(sensors: &[Sensor], size: i32) -> Option<Point> {
+    // instead of checking all points in the square if they are not covered by any sensor, we can restrict the search
+    // to a few candidate points using the assumption that there at most one solution
+    // this single point must either be a corner of the square or it must be next to the covered area of two different
+    // sensors (i.e., it must be on the exterior of two different sensors), because each point that is not in the corner
+    // has at least 5 neighbors that are also within the square (even if the point is on the edge), which all must be
+    // covered by a sensor, otherwise the point would not be the only solution, but the exterior of one sensor can only
+    // cover at most three neighbors of a point (if the point is not covered by the sensor)
+    // thus, the possible candidates are usually only the points in the corners of the square or the intersection points
+    // of exteriors of the sensors
+    // the exteriors through the point can still be parallel if the point is in a diagonal gap of width one between two
+    // sensors whose other neighbors are covered by sensors further away, or if it is on the edge of the square, so in
+    // that (for the puzzle inputs, unlikely) case we fall back to scanning all rows
+    let mut candidates = HashSet::new();
+    candidates.extend(&[(0, 0), (0, size), (size, 0), (size, size)]);
+    candidates.extend(
+        sensors
+            .iter()
+            .flat_map(|s| s.exterior())
+            .tuple_combinations()
+            .flat_map(|(e1, e2)| e1.intersections(&e2)),
+    );
+
+    candidates
+        .into_iter()
+        .find(|&p| {
+            p.0 >= 0
+                && p.0 <= size
+                && p.1 >= 0
+                && p.1 <= size
+                && !sensors.iter().any(|s| s.covers(p))
+        })
+        .or_else(|| {
+            (0..=size).find_map(|y| {
+                let mut intervals = IntervalSet::new();
+                for sensor in sensors {
+                    intervals.insert(sensor.y_interval(y));
+                }
+                intervals.first_missing(0, size).map(|x| (x, y))
+            })
+        })
+}
+
+pub struct Day15 {
+    /// The row in which to count the positions where no beacon can be.
+    pub row: i32,
+    /// The maximum coordinate of the distress beacon.
+    pub size: i32,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            size: 4000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Sensor>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(Sensor::parse).collect()
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> Result<Answer, SolveError> {
+        let mut intervals = IntervalSet::new();
+        let mut beacons = HashSet::new();
+        for sensor in sensors {
+            if sensor.beacon.1 == self.row {
+                beacons.insert(sensor.beacon);
+            }
+
+            intervals.insert(sensor.y_interval(self.row));
+        }
+
+        Ok((intervals.area() - beacons.len() as i32).into())
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let p = find_uncovered_point(sensors, self.size).expect("no uncovered point found");
+        common::extras::add("x", p.0);
+        common::extras::add("y", p.1);
+        Ok(Some((p.0 as i64 * 4000000 + p.1 as i64).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day() -> Day15 {
+        Day15 { row: 10, size: 20 }
+    }
+
+    const SAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn part1() {
+        let input = day().parse(SAMPLE).unwrap();
+        assert_eq!(day().part1(&input).unwrap(), 26.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = day().parse(SAMPLE).unwrap();
+        assert_eq!(day().part2(&input).unwrap(), Some(56000011i64.into()));
+    }
+}
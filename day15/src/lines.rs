@@ -1,7 +1,9 @@
+//! Diagonal line segments on the integer grid.
+
 pub type Point = (i32, i32);
 
-// a line with integer coordinates and a slope of 45 or -45 degrees
-#[derive(Clone, Copy)]
+/// A line segment with integer coordinates and a slope of 45 or -45 degrees.
+#[derive(Debug, Clone, Copy)]
 pub struct Line {
     start: Point,
     end: Point,
@@ -17,6 +19,10 @@ impl Line {
         }
     }
 
+    /// The points with integer coordinates where the two lines cross.
+    ///
+    /// Lines that cross between integer points yield the two points next to the crossing, and only crossings of a
+    /// line with a slope of 1 and a line with a slope of -1 are found.
     pub fn intersections(&self, other: &Self) -> Vec<Point> {
         if self.start == other.start {
             return vec![self.start];
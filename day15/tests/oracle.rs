@@ -0,0 +1,50 @@
+//! Compares the candidate points of part 2 against a scan of the whole square.
+
+use day15::{find_uncovered_point, lines::Point, Sensor};
+use proptest::prelude::*;
+
+fn distance(p1: Point, p2: Point) -> i32 {
+    (p1.0 - p2.0).abs() + (p1.1 - p2.1).abs()
+}
+
+/// A square with sensors that do not cover a hidden point inside it, most of them ending just before the point.
+fn scenario() -> impl Strategy<Value = (i32, Vec<Sensor>)> {
+    (1..=20).prop_flat_map(|size| {
+        let sensor = (-5..=size + 5, -5..=size + 5, 1..=3);
+        let hidden = (0..=size, 0..=size);
+        (Just(size), hidden, prop::collection::vec(sensor, 1..10)).prop_map(
+            |(size, hidden, sensors)| {
+                let sensors = sensors
+                    .into_iter()
+                    .filter_map(|(x, y, slack)| {
+                        let range = distance((x, y), hidden) - slack;
+                        (range >= 0).then(|| Sensor::new((x, y), (x + range, y)))
+                    })
+                    .collect();
+                (size, sensors)
+            },
+        )
+    })
+}
+
+fn uncovered_points(sensors: &[Sensor], size: i32) -> Vec<Point> {
+    (0..=size)
+        .flat_map(|x| (0..=size).map(move |y| (x, y)))
+        .filter(|&p| !sensors.iter().any(|s| s.covers(p)))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn finds_the_only_uncovered_point((size, sensors) in scenario()) {
+        let uncovered = uncovered_points(&sensors, size);
+        let found = find_uncovered_point(&sensors, size);
+
+        if let Some(p) = found {
+            prop_assert!(uncovered.contains(&p), "{:?} is covered", p);
+        }
+        if let [p] = uncovered[..] {
+            prop_assert_eq!(found, Some(p));
+        }
+    }
+}
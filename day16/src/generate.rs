@@ -0,0 +1,77 @@
+//! Random valve scans.
+
+use std::collections::BTreeSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Generates a connected network of `AA` and `size` other valves (at most 63), of which at most 15 have a flow rate.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let count = size.clamp(1, 63) + 1;
+
+    let mut names = vec!["AA".to_owned()];
+    while names.len() < count {
+        let name: String = (0..2).map(|_| rng.gen_range('A'..='Z')).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // a random spanning tree keeps the network connected, the other tunnels add cycles
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.gen_range(0..i));
+    }
+    for _ in 0..count / 2 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut flow_rates = vec![0; count];
+    let mut flowing: Vec<usize> = (1..count).collect();
+    flowing.shuffle(rng);
+    for &i in flowing.iter().take((count / 3).clamp(1, 15)) {
+        flow_rates[i] = rng.gen_range(1..=25);
+    }
+
+    let mut lines: Vec<String> = (0..count)
+        .map(|i| {
+            let neighbors: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+            let tunnels = if neighbors.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {}\n",
+                names[i],
+                flow_rates[i],
+                neighbors.join(", ")
+            )
+        })
+        .collect();
+
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day16;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 8);
+            Day16.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
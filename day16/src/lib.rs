@@ -0,0 +1,383 @@
+//! Day 16: Proboscidea Volcanium
+
+use std::{collections::HashMap, hash::Hash};
+
+use bitset_core::BitSet;
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use itertools::Itertools;
+
+pub mod generate;
+
+/// A valve and the tunnels leading from it to other valves.
+#[derive(Debug)]
+pub struct Valve {
+    flow_rate: u32,
+    neighbors: Vec<String>,
+    index: usize,
+}
+
+impl Valve {
+    /// Parses a valve, adding an error for each of its neighbours to `references` in case the neighbour does not
+    /// exist.
+    fn parse<'a>(
+        mut line: Line<'a>,
+        index: usize,
+        references: &mut Vec<(&'a str, ParseError)>,
+    ) -> Result<(String, Self), ParseError> {
+        line.expect("Valve ")?;
+        let name = line.word()?.to_owned();
+        line.expect(" has flow rate=")?;
+        let flow_rate = line.integer()?;
+        line.expect("; ")?;
+        line.one_of(&[
+            ("tunnels lead to valves ", ()),
+            ("tunnel leads to valve ", ()),
+        ])?;
+
+        let mut neighbors = Vec::new();
+        loop {
+            let column = line.column();
+            let neighbor = line.word()?;
+            let error = ParseError::new(line.number(), column, "the name of a valve")
+                .found(format!("{neighbor:?}"));
+            references.push((neighbor, error));
+            neighbors.push(neighbor.to_owned());
+
+            if !line.eat(", ") {
+                break;
+            }
+        }
+        line.end()?;
+
+        let valve = Self {
+            flow_rate,
+            neighbors,
+            index,
+        };
+        Ok((name, valve))
+    }
+
+    pub fn flow_rate(&self) -> u32 {
+        self.flow_rate
+    }
+
+    /// The names of the valves that can be reached through a tunnel.
+    pub fn neighbors(&self) -> &[String] {
+        &self.neighbors
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct State1<'a> {
+    pos: &'a str,
+    closed_valves: u64,
+}
+
+fn max_pressure1<'a>(
+    valves: &'a HashMap<String, Valve>,
+    valve_combinations: &[u64],
+    minute: u32,
+) -> HashMap<State1<'a>, u32> {
+    if minute == 30 {
+        return HashMap::new();
+    }
+
+    let next_pressure = max_pressure1(valves, valve_combinations, minute + 1);
+
+    let _span = tracing::debug_span!("minute", minute).entered();
+
+    valves
+        .iter()
+        .flat_map(|(pos, valve)| {
+            valve_combinations.iter().filter_map(|&closed_valves| {
+                // staying at position
+                let mut pressure = *next_pressure
+                    .get(&State1 { pos, closed_valves })
+                    .unwrap_or(&0);
+
+                // current valve is opened
+                if closed_valves.bit_test(valve.index) {
+                    let mut new_closed_values = closed_valves;
+                    new_closed_values.bit_reset(valve.index);
+
+                    pressure = pressure.max(
+                        valve.flow_rate * (30 - minute)
+                            + *next_pressure
+                                .get(&State1 {
+                                    pos,
+                                    closed_valves: new_closed_values,
+                                })
+                                .unwrap_or(&0),
+                    );
+                }
+
+                // move to some neighbor
+                for n in &valve.neighbors {
+                    pressure = pressure.max(
+                        *next_pressure
+                            .get(&State1 {
+                                pos: n,
+                                closed_valves,
+                            })
+                            .unwrap_or(&0),
+                    );
+                }
+
+                (pressure > 0).then_some((State1 { pos, closed_valves }, pressure))
+            })
+        })
+        .collect()
+}
+
+struct States2 {
+    valves_count: usize,
+    pressures: Vec<HashMap<u64, u32>>,
+}
+
+impl States2 {
+    fn new(valves_count: usize) -> Self {
+        Self {
+            valves_count,
+            pressures: vec![HashMap::new(); valves_count * valves_count],
+        }
+    }
+
+    fn get(&self, pos1: usize, pos2: usize, closed_valves: u64) -> u32 {
+        *self.pressures[self.index(pos1, pos2)]
+            .get(&closed_valves)
+            .unwrap_or(&0)
+    }
+
+    fn set(&mut self, pos1: usize, pos2: usize, closed_valves: u64, pressure: u32) {
+        let idx = self.index(pos1, pos2);
+        self.pressures[idx].insert(closed_valves, pressure);
+    }
+
+    fn index(&self, pos1: usize, pos2: usize) -> usize {
+        pos2 * self.valves_count + pos1
+    }
+}
+
+fn max_pressure2(
+    valves: &HashMap<String, Valve>,
+    valve_combinations: &[u64],
+    minute: u32,
+    result: &mut States2,
+    next_pressure: &mut States2,
+) {
+    if minute == 30 {
+        *result = States2::new(valves.len());
+        return;
+    }
+
+    max_pressure2(
+        valves,
+        valve_combinations,
+        minute + 1,
+        next_pressure,
+        result,
+    );
+
+    let _span = tracing::debug_span!("minute", minute).entered();
+
+    for valve1 in valves.values() {
+        let pos1 = valve1.index;
+        for valve2 in valves.values() {
+            let pos2 = valve2.index;
+            for &closed_valves in valve_combinations {
+                // both staying at position
+                let mut pressure = next_pressure.get(pos1, pos2, closed_valves);
+
+                let mut check = |new_pressure: u32,
+                                 new_pos1: usize,
+                                 new_pos2: usize,
+                                 new_closed_valves: u64| {
+                    pressure = pressure.max(
+                        new_pressure + next_pressure.get(new_pos1, new_pos2, new_closed_valves),
+                    );
+                };
+
+                // both valves are opened
+                if closed_valves.bit_test(valve1.index) && closed_valves.bit_test(valve2.index) {
+                    let new_pressure = if pos1 == pos2 {
+                        valve1.flow_rate * (30 - minute)
+                    } else {
+                        valve1.flow_rate * (30 - minute) + valve2.flow_rate * (30 - minute)
+                    };
+
+                    let mut new_closed_values = closed_valves;
+                    new_closed_values.bit_reset(valve1.index);
+                    new_closed_values.bit_reset(valve2.index);
+
+                    check(new_pressure, pos1, pos2, new_closed_values);
+                }
+
+                // only first valve opened and moving to neighbor
+                if closed_valves.bit_test(valve1.index) {
+                    let new_pressure = valve1.flow_rate * (30 - minute);
+
+                    let mut new_closed_values = closed_valves;
+                    new_closed_values.bit_reset(valve1.index);
+
+                    for n in &valve2.neighbors {
+                        check(new_pressure, pos1, valves[n].index, new_closed_values);
+                    }
+                }
+
+                // only second valve opened and moving to neighbor
+                if closed_valves.bit_test(valve2.index) {
+                    let new_pressure = valve2.flow_rate * (30 - minute);
+
+                    let mut new_closed_values = closed_valves;
+                    new_closed_values.bit_reset(valve2.index);
+
+                    for n in &valve1.neighbors {
+                        check(new_pressure, valves[n].index, pos2, new_closed_values);
+                    }
+                }
+
+                // both moving to neighbors
+                for n1 in &valve1.neighbors {
+                    for n2 in &valve2.neighbors {
+                        check(0, valves[n1].index, valves[n2].index, closed_valves);
+                    }
+                }
+
+                if pressure > 0 {
+                    result.set(pos1, pos2, closed_valves, pressure)
+                }
+            }
+        }
+    }
+}
+
+/// The network of valves, by name.
+pub struct Tunnels {
+    valves: HashMap<String, Valve>,
+    valve_combinations: Vec<u64>,
+    initial_closed_valves: u64,
+}
+
+impl Tunnels {
+    pub fn valves(&self) -> &HashMap<String, Valve> {
+        &self.valves
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Tunnels;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut references = Vec::new();
+        let valves: HashMap<String, Valve> = parse::lines(input)
+            .enumerate()
+            .map(|(i, l)| Valve::parse(l, i, &mut references))
+            .collect::<Result<_, _>>()?;
+
+        if let Some((_, error)) = references
+            .into_iter()
+            .find(|(name, _)| !valves.contains_key(*name))
+        {
+            return Err(error);
+        }
+        if !valves.contains_key("AA") {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::end_of_input(line, "a valve named \"AA\""));
+        }
+
+        let nonzero_valves = valves.values().filter(|v| v.flow_rate > 0).map(|v| v.index);
+
+        let valve_combinations: Vec<u64> = nonzero_valves
+            .clone()
+            .powerset()
+            .map(|indices| {
+                let mut closed_valves = 0;
+                for idx in indices {
+                    closed_valves.bit_set(idx);
+                }
+
+                closed_valves
+            })
+            .collect();
+
+        let mut initial_closed_valves = 0;
+        for idx in nonzero_valves {
+            initial_closed_valves.bit_set(idx);
+        }
+
+        Ok(Tunnels {
+            valves,
+            valve_combinations,
+            initial_closed_valves,
+        })
+    }
+
+    fn part1(&self, tunnels: &Self::Input) -> Result<Answer, SolveError> {
+        let result = max_pressure1(&tunnels.valves, &tunnels.valve_combinations, 1);
+
+        Ok(result
+            .get(&State1 {
+                pos: "AA",
+                closed_valves: tunnels.initial_closed_valves,
+            })
+            .copied()
+            .unwrap_or(0)
+            .into())
+    }
+
+    fn part2(&self, tunnels: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let mut result = States2::new(tunnels.valves.len());
+        let mut next_pressure = States2::new(tunnels.valves.len());
+        max_pressure2(
+            &tunnels.valves,
+            &tunnels.valve_combinations,
+            5,
+            &mut result,
+            &mut next_pressure,
+        );
+
+        let start_pos = tunnels.valves["AA"].index;
+        Ok(Some(
+            result
+                .get(start_pos, start_pos, tunnels.initial_closed_valves)
+                .into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+    #[test]
+    fn part1() {
+        let input = Day16.parse(SAMPLE).unwrap();
+        assert_eq!(Day16.part1(&input).unwrap(), 1651.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day16.parse(SAMPLE).unwrap();
+        assert_eq!(Day16.part2(&input).unwrap(), Some(1707.into()));
+    }
+}
@@ -0,0 +1,32 @@
+//! Random jet patterns.
+
+use rand::Rng;
+
+/// Generates a pattern of `size` jets (at least 100).
+///
+/// Much shorter patterns can push all rocks away from one side of the chamber, so that the surface of the tower never
+/// repeats and no cycle can be skipped.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut pattern: String = (0..size.max(100))
+        .map(|_| if rng.gen() { '<' } else { '>' })
+        .collect();
+    pattern.push('\n');
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day17.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
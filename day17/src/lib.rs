@@ -0,0 +1,317 @@
+//! Day 17: Pyroclastic Flow
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use grid::{DenseGrid, Grid, Point};
+use visualize::Frame;
+
+pub mod generate;
+
+struct RockColumn {
+    offset: usize,
+    height: usize,
+}
+
+type Rock = &'static [RockColumn];
+
+const ROCKS: &[Rock] = &[
+    &[
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+    ],
+    &[
+        RockColumn {
+            offset: 1,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 3,
+        },
+        RockColumn {
+            offset: 1,
+            height: 1,
+        },
+    ],
+    &[
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 1,
+        },
+        RockColumn {
+            offset: 0,
+            height: 3,
+        },
+    ],
+    &[RockColumn {
+        offset: 0,
+        height: 4,
+    }],
+    &[
+        RockColumn {
+            offset: 0,
+            height: 2,
+        },
+        RockColumn {
+            offset: 0,
+            height: 2,
+        },
+    ],
+];
+
+#[derive(PartialEq, Eq, Hash)]
+struct CycleKey {
+    rock_index: usize,
+    movement_index: usize,
+    /// The depth of the topmost filled cell of each column below the maximum height.
+    surface: [usize; 7],
+}
+
+struct CycleValue {
+    rock_count: usize,
+    height: usize,
+}
+
+#[derive(Debug)]
+struct Cycle {
+    length: usize,
+    height: usize,
+}
+
+struct Map {
+    rock_count: usize,
+    /// The cells of the chamber, with `y` being the height above the floor.
+    filled: DenseGrid<bool>,
+    max_height: usize,
+    cycle_candidates: HashMap<CycleKey, CycleValue>,
+}
+
+impl Map {
+    fn new() -> Self {
+        Self {
+            rock_count: 0,
+            filled: DenseGrid::new(7, 0, false),
+            max_height: 0,
+            cycle_candidates: HashMap::new(),
+        }
+    }
+
+    fn place(
+        &mut self,
+        rock: Rock,
+        rock_index: usize,
+        mut movement: impl Iterator<Item = (usize, isize)>,
+    ) -> Option<Cycle> {
+        let mut left = 2usize;
+        let mut height = self.max_height + 3;
+        let mut movement_index;
+
+        loop {
+            let (idx, step) = movement.next().unwrap();
+            movement_index = idx;
+
+            if let Some(new_left) = left.checked_add_signed(step) {
+                if new_left + rock.len() <= 7 && self.is_valid(rock, new_left, height) {
+                    left = new_left;
+                }
+            }
+
+            if height > 0 && self.is_valid(rock, left, height - 1) {
+                height -= 1;
+            } else {
+                break;
+            }
+        }
+
+        self.rock_count += 1;
+
+        for (i, column) in rock.iter().enumerate() {
+            let base_height = height + column.offset;
+            for h in 0..column.height {
+                self.fill(left + i, base_height + h);
+            }
+        }
+        visualize::emit(|| self.render());
+
+        self.detect_cycle(rock_index, movement_index)
+    }
+
+    fn is_valid(&self, rock: Rock, left: usize, height: usize) -> bool {
+        rock.iter().enumerate().all(|(i, column)| {
+            (0..column.height).all(|h| !self.is_filled(left + i, height + column.offset + h))
+        })
+    }
+
+    fn fill(&mut self, left: usize, height: usize) {
+        self.filled.grow_to_height(height as i32 + 1, false);
+        self.filled[Point::new(left as i32, height as i32)] = true;
+        self.max_height = self.max_height.max(height + 1);
+    }
+
+    fn surface(&self) -> [usize; 7] {
+        let mut surface = [self.max_height; 7];
+        for (column, depth) in surface.iter_mut().enumerate() {
+            if let Some(d) =
+                (0..self.max_height).position(|d| self.is_filled(column, self.max_height - 1 - d))
+            {
+                *depth = d;
+            }
+        }
+
+        surface
+    }
+
+    fn detect_cycle(&mut self, rock_index: usize, movement_index: usize) -> Option<Cycle> {
+        // the same rock falling with the same jet onto the same surface will lead to the same sequence of states
+        let entry = self.cycle_candidates.entry(CycleKey {
+            rock_index,
+            movement_index,
+            surface: self.surface(),
+        });
+
+        match entry {
+            Entry::Occupied(e) => {
+                let prev = e.get();
+                Some(Cycle {
+                    length: self.rock_count - prev.rock_count,
+                    height: self.max_height - prev.height,
+                })
+            }
+            Entry::Vacant(e) => {
+                e.insert(CycleValue {
+                    height: self.max_height,
+                    rock_count: self.rock_count,
+                });
+                None
+            }
+        }
+    }
+
+    /// Renders the top of the chamber (at most `VIEW_HEIGHT` rows above the floor) like the puzzle description.
+    fn render(&self) -> Frame {
+        const VIEW_HEIGHT: usize = 40;
+        let top = self.max_height.max(VIEW_HEIGHT);
+        Frame::from_fn(9, VIEW_HEIGHT + 1, |x, y| {
+            let floor = y == VIEW_HEIGHT && top == VIEW_HEIGHT;
+            match (x, floor) {
+                (0 | 8, true) => '+',
+                (_, true) => '-',
+                (0 | 8, false) => '|',
+                _ if y == VIEW_HEIGHT => '~',
+                _ if self.is_filled(x - 1, top - 1 - y) => '#',
+                _ => '.',
+            }
+        })
+    }
+
+    fn is_filled(&self, left: usize, height: usize) -> bool {
+        self.filled
+            .get(Point::new(left as i32, height as i32))
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+/// The height of the tower after `steps` rocks have fallen, pushed by the jets (`-1` for left, `1` for right).
+///
+/// Once the tower repeats itself, whole cycles are skipped instead of simulated.
+pub fn solve(jets: &[isize], steps: usize) -> usize {
+    let mut movement = jets.iter().copied().enumerate().cycle();
+
+    let mut map = Map::new();
+
+    let mut remaining = steps;
+    let mut cycle_height = 0;
+    for (idx, rock) in ROCKS.iter().enumerate().cycle() {
+        if remaining == 0 {
+            break;
+        }
+
+        let cycle = map.place(rock, idx, &mut movement);
+        remaining -= 1;
+
+        if let Some(cycle) = cycle {
+            // skip as many whole cycles as possible for the rocks that are still left to fall
+            cycle_height += cycle.height * (remaining / cycle.length);
+            remaining %= cycle.length;
+        }
+    }
+
+    cycle_height + map.max_height
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    /// The horizontal movement of each jet of hot gas.
+    type Input = Vec<isize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+        let mut line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(1, "a jet pattern"))?;
+
+        let mut jets = Vec::new();
+        while !line.is_empty() {
+            jets.push(line.one_of(&[("<", -1), (">", 1)])?);
+        }
+
+        if let Some(line) = lines.next() {
+            return Err(line.error("end of input"));
+        }
+
+        Ok(jets)
+    }
+
+    fn part1(&self, jets: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve(jets, 2022).into())
+    }
+
+    fn part2(&self, jets: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(solve(jets, 1000000000000).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn part1() {
+        let input = Day17.parse(SAMPLE).unwrap();
+        assert_eq!(Day17.part1(&input).unwrap(), 3068.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day17.parse(SAMPLE).unwrap();
+        assert_eq!(Day17.part2(&input).unwrap(), Some(1514285714288u64.into()));
+    }
+}
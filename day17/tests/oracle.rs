@@ -0,0 +1,62 @@
+//! Compares the cycle skipping of the tower simulation against simulating every rock.
+
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+/// The cells of each rock relative to its bottom left corner.
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+fn tower_height(jets: &[isize], steps: usize) -> usize {
+    let mut filled = HashSet::new();
+    let mut height = 0;
+    let mut jets = jets.iter().cycle();
+
+    for rock in ROCKS.iter().cycle().take(steps) {
+        let fits = |x: i64, y: i64| {
+            rock.iter().all(|&(dx, dy)| {
+                let (x, y) = (x + dx, y + dy);
+                (0..7).contains(&x) && y >= 0 && !filled.contains(&(x, y))
+            })
+        };
+
+        let (mut x, mut y) = (2, height + 3);
+        loop {
+            let pushed = x + *jets.next().unwrap() as i64;
+            if fits(pushed, y) {
+                x = pushed;
+            }
+
+            if fits(x, y - 1) {
+                y -= 1;
+            } else {
+                break;
+            }
+        }
+
+        for &(dx, dy) in *rock {
+            filled.insert((x + dx, y + dy));
+            height = height.max(y + dy + 1);
+        }
+    }
+
+    height as usize
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn matches_plain_simulation(
+        jets in prop::collection::vec(prop_oneof![Just(-1), Just(1)], 1..30),
+        steps in 0..1500usize,
+    ) {
+        prop_assert_eq!(day17::solve(&jets, steps), tower_height(&jets, steps));
+    }
+}
@@ -0,0 +1,45 @@
+//! Random droplet scans.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+/// Generates `size` different cubes in a box that is about three times their volume.
+///
+/// The coordinates start at 1, so that the steam can flow around the droplet from the origin.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let max = ((size * 3) as f64).cbrt().ceil() as i32 + 1;
+
+    let mut cubes = HashSet::new();
+    let mut output = String::new();
+    while cubes.len() < size {
+        let cube = (
+            rng.gen_range(1..=max),
+            rng.gen_range(1..=max),
+            rng.gen_range(1..=max),
+        );
+        if cubes.insert(cube) {
+            output.push_str(&format!("{},{},{}\n", cube.0, cube.1, cube.2));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day18;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day18.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
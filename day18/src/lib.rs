@@ -0,0 +1,144 @@
+//! Day 18: Boiling Boulders
+
+use std::collections::HashSet;
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// The position of a cube. Coordinates are expected to be non-negative.
+pub type Pos = (i32, i32, i32);
+
+fn neighbors((x, y, z): Pos) -> impl Iterator<Item = Pos> {
+    [-1, 1]
+        .into_iter()
+        .flat_map(move |d| [(x + d, y, z), (x, y + d, z), (x, y, z + d)])
+}
+
+fn fill_steam(seen_cubes: &mut HashSet<Pos>, max_dim: i32) {
+    let valid = |n| n >= 0 && n <= max_dim + 1;
+    let steam = search::bfs(
+        [(0, 0, 0)],
+        |&p| {
+            neighbors(p)
+                .filter(|n| valid(n.0) && valid(n.1) && valid(n.2) && !seen_cubes.contains(n))
+        },
+        |_| false,
+    );
+
+    let steam: Vec<Pos> = steam.visited().copied().collect();
+    seen_cubes.extend(steam);
+}
+
+/// Returns the set of cubes, the largest coordinate of any cube and the number of sides that are not connected to
+/// another cube.
+pub fn surface_area(cubes: &[Pos]) -> (HashSet<Pos>, i32, i32) {
+    let mut max_dim = 0;
+    let mut seen_cubes = HashSet::new();
+    let mut surface_area = 0;
+
+    for &pos in cubes {
+        max_dim = max_dim.max(pos.0).max(pos.1).max(pos.2);
+
+        seen_cubes.insert(pos);
+        for neighbor in neighbors(pos) {
+            if seen_cubes.contains(&neighbor) {
+                // the surface of the other cube is now covered
+                surface_area -= 1;
+            } else {
+                // the surface of the cube is (currently) not covered
+                surface_area += 1;
+            }
+        }
+    }
+
+    (seen_cubes, max_dim, surface_area)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Pos>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|mut line| {
+                let x = line.integer()?;
+                line.expect(",")?;
+                let y = line.integer()?;
+                line.expect(",")?;
+                let z = line.integer()?;
+                line.end()?;
+
+                Ok((x, y, z))
+            })
+            .collect()
+    }
+
+    fn part1(&self, cubes: &Self::Input) -> Result<Answer, SolveError> {
+        let (_, _, surface_area) = surface_area(cubes);
+        Ok(surface_area.into())
+    }
+
+    fn part2(&self, cubes: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let (mut seen_cubes, max_dim, surface_area) = surface_area(cubes);
+
+        fill_steam(&mut seen_cubes, max_dim);
+
+        let mut inside_surface_area = 0;
+        for x in 0..=max_dim {
+            for y in 0..=max_dim {
+                for z in 0..=max_dim {
+                    let pos = (x, y, z);
+                    if !seen_cubes.contains(&pos) {
+                        for neighbor in neighbors(pos) {
+                            if seen_cubes.contains(&neighbor) {
+                                inside_surface_area += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Some((surface_area - inside_surface_area).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+    #[test]
+    fn part1() {
+        let input = Day18.parse(SAMPLE).unwrap();
+        assert_eq!(Day18.part1(&input).unwrap(), 64.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day18.parse(SAMPLE).unwrap();
+        assert_eq!(Day18.part2(&input).unwrap(), Some(58.into()));
+    }
+}
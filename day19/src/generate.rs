@@ -0,0 +1,38 @@
+//! Random blueprints.
+
+use rand::Rng;
+
+/// Generates `size` blueprints with costs in the ranges of the puzzle input.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+                rng.gen_range(2..=4),
+                rng.gen_range(5..=20),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day19;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 2);
+            Day19.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
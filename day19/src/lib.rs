@@ -0,0 +1,205 @@
+//! Day 19: Not Enough Minerals
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use enum_map::{enum_map, Enum, EnumMap};
+use good_lp::{
+    constraint, default_solver, variable, Expression, ProblemVariables, Solution as _, SolverModel,
+    Variable,
+};
+use rayon::prelude::*;
+use Resource::*;
+
+pub mod generate;
+
+#[derive(Enum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+/// The costs of the robots: `costs[robot][resource]` is the amount of `resource` needed to build a `robot`.
+pub struct Blueprint {
+    costs: EnumMap<Resource, EnumMap<Resource, u32>>,
+}
+
+const RESOURCE_NAMES: [(&str, Resource); 4] = [
+    ("ore", Ore),
+    ("clay", Clay),
+    ("obsidian", Obsidian),
+    ("geode", Geode),
+];
+
+impl Blueprint {
+    fn parse(mut line: Line) -> Result<(u32, Self), ParseError> {
+        line.expect("Blueprint ")?;
+        let id = line.integer()?;
+        line.expect(":")?;
+
+        let mut costs = enum_map! { _ => enum_map! { _ => 0 } };
+        for (name, robot) in RESOURCE_NAMES {
+            line.expect(&format!(" Each {name} robot costs "))?;
+            costs[robot] = parse_price(&mut line)?;
+            line.expect(".")?;
+        }
+        line.end()?;
+
+        Ok((id, Self { costs }))
+    }
+}
+
+/// Parses a list of amounts like "3 ore and 14 clay".
+fn parse_price(line: &mut Line) -> Result<EnumMap<Resource, u32>, ParseError> {
+    let mut result = enum_map! {
+        _ => 0
+    };
+
+    loop {
+        let amount = line.integer()?;
+        line.expect(" ")?;
+        let resource = line.one_of(&RESOURCE_NAMES[..3])?;
+        result[resource] = amount;
+
+        if !line.eat(" and ") {
+            return Ok(result);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ResourceVars {
+    amounts: Vec<Variable>,
+    robots: Vec<Variable>,
+    robots_built: Vec<Variable>,
+}
+
+/// The largest number of geodes that can be opened in `minutes` minutes, found by solving an integer linear program.
+pub fn max_geodes(blueprint: &Blueprint, minutes: usize) -> u32 {
+    let mut vars = ProblemVariables::new();
+
+    let resource_vars: EnumMap<Resource, ResourceVars> = enum_map! {
+        _ => ResourceVars {
+            amounts: vars.add_vector(variable().integer().min(0), minutes),
+            robots: vars.add_vector(variable().integer().min(0), minutes),
+            robots_built: vars.add_vector(variable().binary(), minutes),
+        }
+    };
+
+    let target = resource_vars[Geode].amounts[minutes - 1];
+
+    let mut model = vars.maximise(target).using(default_solver);
+    model.set_parameter("log", "0");
+
+    for minute in 0..minutes {
+        for (resource, vars) in &resource_vars {
+            let amount = vars.amounts[minute];
+            // a robot built in minute n only produces resources in minute n+2
+            // note: in the original problem description, this corresponds to a robot built in minute n+1
+            //       but we have to ensure that a robot cannot be build from resources that were just gathered
+            // note: this does not apply to the starting ore robot
+            let mut gathered: Expression = if minute > 1 {
+                vars.robots[minute - 2].into()
+            } else {
+                0.into()
+            };
+            gathered += if resource == Ore { 1 } else { 0 };
+
+            let amount_constraint = if minute == 0 {
+                // start with no resources
+                constraint!(amount == gathered)
+            } else {
+                // new resources are previous resources + resources gathered - resources spent
+                let spent = blueprint
+                    .costs
+                    .iter()
+                    .map(|(r, c)| resource_vars[r].robots_built[minute] * c[resource])
+                    .sum::<Expression>();
+
+                let new_value = vars.amounts[minute - 1] + gathered - spent;
+                constraint!(amount == new_value)
+            };
+            model.add_constraint(amount_constraint);
+
+            let robots = vars.robots[minute];
+            let robots_constraint = if minute == 0 {
+                // start with no additional robots (the one ore robot is incorporated in the gathered formula above)
+                constraint!(robots == 0)
+            } else {
+                let new_value = vars.robots[minute - 1] + vars.robots_built[minute];
+                constraint!(robots == new_value)
+            };
+
+            model.add_constraint(robots_constraint);
+        }
+
+        // only at most one robot can be built per step
+        let robots_built = resource_vars
+            .values()
+            .map(|vars| vars.robots_built[minute])
+            .sum::<Expression>();
+        model.add_constraint(constraint!(robots_built <= 1));
+    }
+
+    let solution = model.solve().unwrap();
+    solution.value(target) as u32
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    /// The blueprints together with their ids.
+    type Input = Vec<(u32, Blueprint)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(Blueprint::parse).collect()
+    }
+
+    // the blueprints are independent, so their models are built in parallel (the solves themselves are serialized by
+    // coin_cbc, because libcbc is not thread safe)
+    fn part1(&self, blueprints: &Self::Input) -> Result<Answer, SolveError> {
+        let result = blueprints
+            .par_iter()
+            .map(|(id, blueprint)| id * max_geodes(blueprint, 24))
+            .sum::<u32>();
+
+        Ok(result.into())
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let result = blueprints
+            .par_iter()
+            .take(3)
+            .map(|(_, blueprint)| max_geodes(blueprint, 32))
+            .product::<u32>();
+
+        Ok(Some(result.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+    #[test]
+    fn part1() {
+        let input = Day19.parse(SAMPLE).unwrap();
+        assert_eq!(Day19.part1(&input).unwrap(), 33.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day19.parse(SAMPLE).unwrap();
+        assert_eq!(Day19.part2(&input).unwrap(), Some(3472.into()));
+    }
+}
@@ -0,0 +1,149 @@
+//! How good a strategy guide is, given how often the opponent plays each gesture.
+//!
+//! ```
+//! use day2::{analysis::Analysis, game::Game};
+//!
+//! let game = Game::rock_paper_scissors();
+//! // the opponent plays rock twice and scissors once
+//! let analysis = Analysis::new(&game, [0, 0, 2]);
+//! assert_eq!(analysis.best_responses(), [1, 2, 0]);
+//! assert_eq!(analysis.best_total(), 8 + 8 + 7);
+//! assert_eq!(analysis.best_fixed_gesture(), (1, 6.0));
+//! ```
+
+use crate::game::Game;
+
+/// The empirical distribution of the opponent's gestures and the scores that can be achieved against it.
+#[derive(Debug, Clone)]
+pub struct Analysis<'a> {
+    game: &'a Game,
+    /// How often the opponent plays each gesture.
+    counts: Vec<u32>,
+}
+
+impl<'a> Analysis<'a> {
+    /// Analyzes the rounds in which the opponent plays the given gestures.
+    pub fn new(game: &'a Game, opponent: impl IntoIterator<Item = usize>) -> Self {
+        let mut counts = vec![0; game.gestures()];
+        for gesture in opponent {
+            counts[gesture] += 1;
+        }
+
+        Self { game, counts }
+    }
+
+    pub fn rounds(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// The share of the rounds in which the opponent plays each gesture.
+    pub fn distribution(&self) -> Vec<f64> {
+        let rounds = self.rounds().max(1) as f64;
+        self.counts.iter().map(|&c| c as f64 / rounds).collect()
+    }
+
+    /// The expected score of a round when always playing `own`.
+    pub fn expected_score(&self, own: usize) -> f64 {
+        (0..self.game.gestures())
+            .zip(self.distribution())
+            .map(|(other, p)| p * self.game.score(own, other) as f64)
+            .sum()
+    }
+
+    /// The gesture with the highest expected score (the first one if several are equally good), for an opponent
+    /// whose next gesture is not known, together with its expected score.
+    pub fn best_fixed_gesture(&self) -> (usize, f64) {
+        (0..self.game.gestures())
+            .map(|own| (own, self.expected_score(own)))
+            .fold((0, f64::MIN), |best, (own, score)| {
+                if score > best.1 {
+                    (own, score)
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// The highest scoring response to each gesture of the opponent, which is the policy that maximizes the expected
+    /// score when the opponent's gesture is known (as in the strategy guide).
+    pub fn best_responses(&self) -> Vec<usize> {
+        (0..self.game.gestures())
+            .map(|other| self.extreme_response(other, true))
+            .collect()
+    }
+
+    /// The highest total score that can be achieved in all rounds.
+    pub fn best_total(&self) -> u32 {
+        self.total(true)
+    }
+
+    /// The lowest total score that can be achieved in all rounds.
+    pub fn worst_total(&self) -> u32 {
+        self.total(false)
+    }
+
+    /// How many points a guide that achieves `total` is away from the best total.
+    pub fn gap(&self, total: u32) -> u32 {
+        self.best_total().saturating_sub(total)
+    }
+
+    fn extreme_response(&self, other: usize, best: bool) -> usize {
+        let score = |own: &usize| self.game.score(*own, other);
+        let responses = 0..self.game.gestures();
+        if best {
+            responses.max_by_key(score)
+        } else {
+            responses.min_by_key(score)
+        }
+        .expect("a game has at least one gesture")
+    }
+
+    fn total(&self, best: bool) -> u32 {
+        self.counts
+            .iter()
+            .enumerate()
+            .map(|(other, &count)| {
+                count * self.game.score(self.extreme_response(other, best), other)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_rounds() {
+        let game = Game::rock_paper_scissors();
+        // the scores of playing rock, paper and scissors against rock, paper and scissors
+        let table = [[4, 8, 3], [1, 5, 9], [7, 2, 6]];
+        for (opponent, scores) in table.iter().enumerate() {
+            let analysis = Analysis::new(&game, [opponent]);
+
+            // the best response wins and the worst one loses
+            assert_eq!(analysis.best_total(), scores.iter().copied().max().unwrap());
+            assert_eq!(
+                analysis.worst_total(),
+                scores.iter().copied().min().unwrap()
+            );
+            assert_eq!(analysis.best_responses()[opponent], (opponent + 1) % 3);
+
+            for (own, &score) in scores.iter().enumerate() {
+                assert_eq!(analysis.expected_score(own), score as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn best_fixed_gesture_follows_the_distribution() {
+        let game = Game::rock_paper_scissors();
+        // against mostly paper, scissors is best
+        let analysis = Analysis::new(&game, [1, 1, 1, 0]);
+        assert_eq!(analysis.distribution(), [0.25, 0.75, 0.0]);
+        assert_eq!(analysis.best_fixed_gesture().0, 2);
+        // rock against paper three times and scissors against rock once
+        assert_eq!(analysis.worst_total(), 6);
+        assert_eq!(analysis.gap(20), 3 * 9 + 8 - 20);
+    }
+}
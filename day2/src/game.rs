@@ -0,0 +1,258 @@
+//! Games like rock paper scissors with any odd number of gestures.
+//!
+//! The gestures of a game are numbered from 0 and form a cycle, in which each gesture beats the gestures an odd number
+//! of steps before it and loses against the gestures an odd number of steps after it. With three gestures, this is
+//! rock paper scissors (each gesture beats the one before it), and with five gestures in the order rock, paper,
+//! scissors, spock, lizard it is rock paper scissors lizard spock.
+//!
+//! ```
+//! use day2::game::{Decoder, Game, Outcome, PlayGesture};
+//!
+//! let game = Game::rock_paper_scissors_lizard_spock();
+//! let (rock, spock, lizard) = (0, 3, 4);
+//! assert_eq!(game.outcome(spock, rock), Outcome::Win);
+//! assert_eq!(game.outcome(spock, lizard), Outcome::Loss);
+//! assert_eq!(game.score(spock, rock), 4 + 6);
+//! assert_eq!(PlayGesture.decode(&game, rock, lizard), lizard);
+//! ```
+
+/// The outcome of a round from the view of one player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    /// The outcome of playing gesture `own` against gesture `other` in a game with `gestures` gestures.
+    pub fn of(own: usize, other: usize, gestures: usize) -> Self {
+        let steps = (own + gestures - other) % gestures;
+        if steps == 0 {
+            Self::Draw
+        } else if steps % 2 == 1 {
+            Self::Win
+        } else {
+            Self::Loss
+        }
+    }
+}
+
+/// The points for the gesture that is played and for the outcome of a round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    /// The points for playing each gesture.
+    pub gestures: Vec<u32>,
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl Scoring {
+    /// The scoring of the puzzle: 1 point for the first gesture, 2 for the second and so on, plus 0 points for a loss, 3
+    /// for a draw and 6 for a win.
+    pub fn standard(gestures: usize) -> Self {
+        Self {
+            gestures: (1..=gestures as u32).collect(),
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+
+    pub fn outcome(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+/// A game with cyclic dominance between its gestures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    scoring: Scoring,
+}
+
+impl Game {
+    /// Creates a game with one gesture for each entry of `scoring.gestures`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of gestures is even, because then some gestures would beat more gestures than others.
+    pub fn new(scoring: Scoring) -> Self {
+        assert!(
+            scoring.gestures.len() % 2 == 1,
+            "a game needs an odd number of gestures"
+        );
+
+        Self { scoring }
+    }
+
+    /// Rock (0), paper (1) and scissors (2) with the standard scoring.
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(Scoring::standard(3))
+    }
+
+    /// Rock (0), paper (1), scissors (2), spock (3) and lizard (4) with the standard scoring.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(Scoring::standard(5))
+    }
+
+    /// The number of gestures.
+    pub fn gestures(&self) -> usize {
+        self.scoring.gestures.len()
+    }
+
+    pub fn scoring(&self) -> &Scoring {
+        &self.scoring
+    }
+
+    pub fn outcome(&self, own: usize, other: usize) -> Outcome {
+        Outcome::of(own, other, self.gestures())
+    }
+
+    /// The score of playing gesture `own` against gesture `other`.
+    pub fn score(&self, own: usize, other: usize) -> u32 {
+        self.scoring.gestures[own] + self.scoring.outcome(self.outcome(own, other))
+    }
+
+    /// The highest scoring gesture that has the given outcome against gesture `other`.
+    pub fn response(&self, other: usize, outcome: Outcome) -> usize {
+        (0..self.gestures())
+            .filter(|&own| self.outcome(own, other) == outcome)
+            .max_by_key(|&own| (self.scoring.gestures[own], std::cmp::Reverse(own)))
+            .expect("every outcome is possible against every gesture")
+    }
+}
+
+/// An interpretation of the second column of the strategy guide.
+pub trait Decoder {
+    /// The gesture to play against the gesture `opponent`, when the second column contains the letter with index
+    /// `column`.
+    fn decode(&self, game: &Game, opponent: usize, column: usize) -> usize;
+
+    /// The number of letters this decoder understands.
+    fn letters(&self, game: &Game) -> usize;
+}
+
+/// The second column is the gesture to play.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlayGesture;
+
+impl Decoder for PlayGesture {
+    fn decode(&self, _game: &Game, _opponent: usize, column: usize) -> usize {
+        column
+    }
+
+    fn letters(&self, game: &Game) -> usize {
+        game.gestures()
+    }
+}
+
+/// The second column is the outcome to achieve, playing the highest scoring gesture with this outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AchieveOutcome {
+    /// The outcome meant by each letter.
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Default for AchieveOutcome {
+    /// The letters mean loss, draw and win.
+    fn default() -> Self {
+        Self {
+            outcomes: Outcome::ALL.to_vec(),
+        }
+    }
+}
+
+impl Decoder for AchieveOutcome {
+    fn decode(&self, game: &Game, opponent: usize, column: usize) -> usize {
+        game.response(opponent, self.outcomes[column])
+    }
+
+    fn letters(&self, _game: &Game) -> usize {
+        self.outcomes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_gesture_beats_half_of_the_others() {
+        for game in [
+            Game::rock_paper_scissors(),
+            Game::rock_paper_scissors_lizard_spock(),
+        ] {
+            let n = game.gestures();
+            for own in 0..n {
+                let wins = (0..n)
+                    .filter(|&other| game.outcome(own, other) == Outcome::Win)
+                    .count();
+                assert_eq!(wins, n / 2);
+
+                for other in 0..n {
+                    let reverse = match game.outcome(own, other) {
+                        Outcome::Loss => Outcome::Win,
+                        Outcome::Draw => Outcome::Draw,
+                        Outcome::Win => Outcome::Loss,
+                    };
+                    assert_eq!(game.outcome(other, own), reverse);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+        let [rock, paper, scissors] = [0, 1, 2];
+        assert_eq!(game.score(rock, scissors), 1 + 6);
+        assert_eq!(game.score(paper, paper), 2 + 3);
+        assert_eq!(game.score(scissors, rock), 3);
+        // the gestures to lose, draw and win against rock
+        let responses = Outcome::ALL.map(|outcome| game.response(rock, outcome));
+        assert_eq!(responses, [scissors, rock, paper]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let [rock, paper, scissors, spock, lizard] = [0, 1, 2, 3, 4];
+        for (winner, loser) in [
+            (scissors, paper),
+            (paper, rock),
+            (rock, lizard),
+            (lizard, spock),
+            (spock, scissors),
+            (scissors, lizard),
+            (lizard, paper),
+            (paper, spock),
+            (spock, rock),
+            (rock, scissors),
+        ] {
+            assert_eq!(game.outcome(winner, loser), Outcome::Win);
+        }
+    }
+
+    #[test]
+    fn response_prefers_higher_scores() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        // paper and spock both beat rock
+        assert_eq!(game.response(0, Outcome::Win), 3);
+        assert_eq!(game.response(0, Outcome::Draw), 0);
+        // lizard and scissors both lose against rock
+        assert_eq!(game.response(0, Outcome::Loss), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number")]
+    fn even_number_of_gestures() {
+        Game::new(Scoring::standard(4));
+    }
+}
@@ -0,0 +1,31 @@
+//! Random strategy guides.
+
+use rand::Rng;
+
+/// Generates a strategy guide of `size` rounds.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let opponent = rng.gen_range(b'A'..=b'C') as char;
+            let own = rng.gen_range(b'X'..=b'Z') as char;
+            format!("{opponent} {own}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day2;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day2::default().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Day 2: Rock Paper Scissors
+//!
+//! The rounds are scored by a [`Game`], which supports any odd number of gestures, and the second column of the
+//! strategy guide is read by a different [`Decoder`] in each part. How far each part's reading of the guide is from the
+//! best possible total is part of the analysis of the guide (`aoc analyze 2`), see [`Analysis`].
+
+use std::fmt::Write;
+
+use analysis::Analysis;
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use game::{AchieveOutcome, Decoder, Game, PlayGesture};
+
+pub mod analysis;
+pub mod game;
+pub mod generate;
+
+pub struct Day2 {
+    pub game: Game,
+    /// How the second column of the strategy guide is read in part 1.
+    pub part1: Box<dyn Decoder + Send + Sync>,
+    /// How the second column of the strategy guide is read in part 2.
+    pub part2: Box<dyn Decoder + Send + Sync>,
+}
+
+impl Default for Day2 {
+    /// Rock paper scissors, where the second column is the gesture to play in part 1 and the outcome to achieve in
+    /// part 2.
+    fn default() -> Self {
+        Self {
+            game: Game::rock_paper_scissors(),
+            part1: Box::new(PlayGesture),
+            part2: Box::new(AchieveOutcome::default()),
+        }
+    }
+}
+
+/// The letters of a column of the strategy guide, together with their indices.
+type Letters = Vec<(String, usize)>;
+
+impl Day2 {
+    /// The letters of the opponent's gestures (starting at A) and of the second column (ending at Z), which has as
+    /// many letters as both decoders understand.
+    fn letters(&self) -> (Letters, Letters) {
+        let letter = |c: u8, i: usize| ((c as char).to_string(), i);
+        let columns = self
+            .part1
+            .letters(&self.game)
+            .min(self.part2.letters(&self.game));
+
+        let opponent = (0..self.game.gestures())
+            .map(|i| letter(b'A' + i as u8, i))
+            .collect();
+        let second = (0..columns)
+            .map(|i| letter(b'Z' + 1 - (columns - i) as u8, i))
+            .collect();
+
+        (opponent, second)
+    }
+
+    /// Analyzes the opponent's gestures of the strategy guide.
+    pub fn analyze(&self, guide: &[(usize, usize)]) -> Analysis<'_> {
+        Analysis::new(&self.game, guide.iter().map(|&(opponent, _)| opponent))
+    }
+
+    /// The total score of following the guide as read by `decoder`.
+    fn score(&self, guide: &[(usize, usize)], decoder: &dyn Decoder) -> u32 {
+        guide
+            .iter()
+            .map(|&(opponent, column)| {
+                let own = decoder.decode(&self.game, opponent, column);
+                self.game.score(own, opponent)
+            })
+            .sum()
+    }
+}
+
+fn one_of(line: &mut Line, letters: &Letters) -> Result<usize, ParseError> {
+    let options: Vec<_> = letters.iter().map(|(s, i)| (s.as_str(), *i)).collect();
+    line.one_of(&options)
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    /// The opponent's gesture and the index of the letter in the second column of each line of the strategy guide.
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let (opponent_letters, second_letters) = self.letters();
+        parse::lines(input)
+            .map(|mut line| {
+                let opponent = one_of(&mut line, &opponent_letters)?;
+                line.expect(" ")?;
+                let second = one_of(&mut line, &second_letters)?;
+                line.end()?;
+
+                Ok((opponent, second))
+            })
+            .collect()
+    }
+
+    fn part1(&self, guide: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(self.score(guide, &*self.part1).into())
+    }
+
+    fn part2(&self, guide: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(self.score(guide, &*self.part2).into()))
+    }
+
+    /// The distribution of the opponent's gestures and the best response to each of them (both by the letters of the
+    /// opponent's gestures in the guide), the best and worst totals and how far each part's total is from the best one.
+    fn analysis(&self, guide: &Self::Input) -> Option<String> {
+        let analysis = self.analyze(guide);
+        let (letters, _) = self.letters();
+
+        let mut report = String::new();
+        let shares: Vec<String> = letters
+            .iter()
+            .zip(analysis.distribution())
+            .map(|((letter, _), share)| format!("{letter} {:.1}%", 100.0 * share))
+            .collect();
+        writeln!(report, "opponent: {}", shares.join(", ")).unwrap();
+
+        let responses: Vec<String> = analysis
+            .best_responses()
+            .into_iter()
+            .enumerate()
+            .map(|(other, own)| format!("{} → {}", letters[other].0, letters[own].0))
+            .collect();
+        writeln!(report, "best responses: {}", responses.join(", ")).unwrap();
+
+        let (gesture, expected) = analysis.best_fixed_gesture();
+        writeln!(
+            report,
+            "best fixed gesture: {} ({expected:.2} per round)",
+            letters[gesture].0
+        )
+        .unwrap();
+        writeln!(report, "best total: {}", analysis.best_total()).unwrap();
+        writeln!(report, "worst total: {}", analysis.worst_total()).unwrap();
+        for (part, decoder) in [(1, &self.part1), (2, &self.part2)] {
+            let total = self.score(guide, &**decoder);
+            let gap = analysis.gap(total);
+            writeln!(report, "part {part}: {total} (gap {gap})").unwrap();
+        }
+
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+A Y
+B X
+C Z
+";
+
+    #[test]
+    fn part1() {
+        let day = Day2::default();
+        let input = day.parse(SAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), 15.into());
+    }
+
+    #[test]
+    fn part2() {
+        let day = Day2::default();
+        let input = day.parse(SAMPLE).unwrap();
+        assert_eq!(day.part2(&input).unwrap(), Some(12.into()));
+    }
+
+    #[test]
+    fn analyze() {
+        let day = Day2::default();
+        let input = day.parse(SAMPLE).unwrap();
+        let analysis = day.analyze(&input);
+        assert_eq!(analysis.best_total(), 8 + 9 + 7);
+        assert_eq!(analysis.gap(15), 9);
+        assert_eq!(analysis.gap(12), 12);
+    }
+
+    #[test]
+    fn analysis() {
+        let day = Day2::default();
+        let input = day.parse(SAMPLE).unwrap();
+        let report = Solution::analysis(&day, &input).unwrap();
+        assert_eq!(
+            report,
+            "opponent: A 33.3%, B 33.3%, C 33.3%\n\
+             best responses: A → B, B → C, C → A\n\
+             best fixed gesture: C (6.00 per round)\n\
+             best total: 24\n\
+             worst total: 6\n\
+             part 1: 15 (gap 9)\n\
+             part 2: 12 (gap 12)\n"
+        );
+    }
+
+    #[test]
+    fn five_gestures() {
+        use game::Outcome::*;
+        let day = Day2 {
+            game: Game::rock_paper_scissors_lizard_spock(),
+            part1: Box::new(PlayGesture),
+            part2: Box::new(AchieveOutcome {
+                outcomes: vec![Loss, Loss, Draw, Win, Win],
+            }),
+        };
+
+        // spock against rock wins, lizard against paper wins
+        let input = day.parse("A Y\nB Z\n").unwrap();
+        assert_eq!(input, [(0, 3), (1, 4)]);
+        assert_eq!(day.part1(&input).unwrap(), (4 + 6 + 5 + 6).into());
+        // the highest scoring winning responses are spock against rock and lizard against paper
+        assert_eq!(day.part2(&input).unwrap(), Some((4 + 6 + 5 + 6).into()));
+        assert!(day.parse("F X\n").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Random encrypted files.
+
+use rand::Rng;
+
+/// Generates a file of `size` numbers, exactly one of which is 0.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let len = size.max(1);
+    let zero = rng.gen_range(0..len);
+
+    (0..len)
+        .map(|i| {
+            let n = if i == zero {
+                0
+            } else {
+                rng.gen_range(1..=10000) * if rng.gen() { 1 } else { -1 }
+            };
+            format!("{n}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day20;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day20.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Day 20: Grove Positioning System
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// Mixes the file `rounds` times, moving each number by its value in the order of the original file.
+pub fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let mut result: Vec<(usize, i64)> = numbers.iter().cloned().enumerate().collect();
+    let len = result.len() as i64;
+
+    let mov = |result: &mut Vec<(usize, i64)>, pos: usize, offset: i64| {
+        let mut target = (pos as i64 + offset).rem_euclid(len - 1) as usize;
+        if target == 0 {
+            target = len as usize - 1;
+        }
+
+        let elem = result.remove(pos);
+        result.insert(target, elem);
+    };
+
+    for _ in 0..rounds {
+        for (i, &n) in numbers.iter().enumerate() {
+            let pos = result.iter().position(|&(p, _)| p == i).unwrap();
+            mov(&mut result, pos, n);
+        }
+    }
+
+    result.into_iter().map(|(_, n)| n).collect()
+}
+
+/// The sum of the numbers 1000, 2000 and 3000 positions after the 0 in the mixed file.
+pub fn grove_coordinates(mixed: &[i64]) -> i64 {
+    let pos0 = mixed.iter().position(|&n| n == 0).unwrap();
+
+    mixed[(pos0 + 1000) % mixed.len()]
+        + mixed[(pos0 + 2000) % mixed.len()]
+        + mixed[(pos0 + 3000) % mixed.len()]
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|mut line| {
+                let number = line.integer()?;
+                line.end()?;
+                Ok(number)
+            })
+            .collect()
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(grove_coordinates(&mix(numbers, 1)).into())
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let numbers: Vec<i64> = numbers.iter().map(|n| n * 811589153).collect();
+        Ok(Some(grove_coordinates(&mix(&numbers, 10)).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4
+";
+
+    #[test]
+    fn part1() {
+        let input = Day20.parse(SAMPLE).unwrap();
+        assert_eq!(Day20.part1(&input).unwrap(), 3.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day20.parse(SAMPLE).unwrap();
+        assert_eq!(Day20.part2(&input).unwrap(), Some(1623178306i64.into()));
+    }
+}
@@ -0,0 +1,116 @@
+//! Random monkey riddles.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Generates the jobs of about `size` monkeys (at least 5), whose numbers form a tree below `root`.
+///
+/// Both operands of `root` yell the same number for some number of `humn`, and all operations on the way to `humn`
+/// can be inverted without remainder, so that part 2 has an exact answer. `humn` itself yells a random number.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut riddle = Riddle {
+        rng: &mut *rng,
+        names: HashSet::from(["root".to_owned(), "humn".to_owned()]),
+        jobs: Vec::new(),
+    };
+
+    let size = size.max(5) - 1;
+    let left_size = riddle.rng.gen_range(1..size);
+    let human_left = riddle.rng.gen();
+    let target = riddle.rng.gen_range(1..=100_000);
+    let left = riddle.monkey(target, left_size, human_left);
+    let right = riddle.monkey(target, size - left_size, !human_left);
+
+    let op = ['+', '-', '*'].choose(riddle.rng).unwrap();
+    riddle.jobs.push(format!("root: {left} {op} {right}\n"));
+
+    let mut jobs = riddle.jobs;
+    jobs.shuffle(rng);
+    jobs.concat()
+}
+
+struct Riddle<'a, R> {
+    rng: &'a mut R,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl<R: Rng> Riddle<'_, R> {
+    /// Adds a monkey that yells `value` with about `size` monkeys below it (including itself) and returns its name.
+    ///
+    /// If `human` is set, `humn` is one of the monkeys below it and `value` is only yelled for the right number of
+    /// `humn`.
+    fn monkey(&mut self, value: i64, size: usize, human: bool) -> String {
+        if size < 3 {
+            return if human {
+                let number = self.rng.gen_range(1..=10_000);
+                self.jobs.push(format!("humn: {number}\n"));
+                "humn".to_owned()
+            } else {
+                self.job(value.to_string())
+            };
+        }
+
+        // pick the operands of an operation that results in `value`
+        let small_divisors: Vec<i64> = (2..=9).filter(|d| value % d == 0).collect();
+        let (op, mut left, mut right) = match self.rng.gen_range(0..4) {
+            0 if value > 1 => {
+                let n = self.rng.gen_range(1..value.min(1000));
+                ('+', value - n, n)
+            }
+            2 if !small_divisors.is_empty() => {
+                let divisor = *small_divisors.choose(self.rng).unwrap();
+                ('*', value / divisor, divisor)
+            }
+            3 if value < 1_000_000_000 => {
+                let divisor = self.rng.gen_range(2..=9);
+                ('/', value * divisor, divisor)
+            }
+            _ => {
+                let n = self.rng.gen_range(1..=1000);
+                ('-', value + n, n)
+            }
+        };
+        if matches!(op, '+' | '*') && self.rng.gen() {
+            std::mem::swap(&mut left, &mut right);
+        }
+
+        // the human is never in the divisor, so that the divisor cannot become 0 for other numbers of `humn`
+        let human_left = op == '/' || self.rng.gen();
+        let left_size = self.rng.gen_range(1..size - 1);
+        let left = self.monkey(left, left_size, human && human_left);
+        let right = self.monkey(right, size - 1 - left_size, human && !human_left);
+        self.job(format!("{left} {op} {right}"))
+    }
+
+    /// Adds a monkey with a new name and the given job.
+    fn job(&mut self, job: String) -> String {
+        let name = loop {
+            let name: String = (0..4).map(|_| self.rng.gen_range('a'..='z')).collect();
+            if self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        self.jobs.push(format!("{name}: {job}\n"));
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day21;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day21.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Day 21: Monkey Math
+
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// The operation of a monkey that combines the numbers of two other monkeys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Plus,
+    Minus,
+    Times,
+    Divide,
+}
+
+impl Op {
+    pub fn eval(&self, n1: i64, n2: i64) -> i64 {
+        match self {
+            Op::Plus => n1 + n2,
+            Op::Minus => n1 - n2,
+            Op::Times => n1 * n2,
+            Op::Divide => n1 / n2,
+        }
+    }
+
+    /// Solves `x op n2 = result` for `x`.
+    pub fn right_inverse(&self, result: i64, n2: i64) -> i64 {
+        match self {
+            Op::Plus => result - n2,
+            Op::Minus => result + n2,
+            Op::Times => result / n2,
+            Op::Divide => result * n2,
+        }
+    }
+
+    /// Solves `n1 op x = result` for `x`.
+    pub fn left_inverse(&self, result: i64, n1: i64) -> i64 {
+        match self {
+            Op::Plus => result - n1,
+            Op::Minus => n1 - result,
+            Op::Times => result / n1,
+            Op::Divide => n1 / result,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Monkey<'a> {
+    Number(i64),
+    Operation(Op, &'a str, &'a str),
+}
+
+impl<'a> Monkey<'a> {
+    /// Parses a monkey, adding an error for each monkey it refers to to `references` in case that monkey does not
+    /// exist.
+    fn parse(
+        mut line: Line<'a>,
+        references: &mut Vec<(&'a str, ParseError)>,
+    ) -> Result<(&'a str, Self), ParseError> {
+        let name = line.word()?;
+        line.expect(": ")?;
+
+        let monkey = if line
+            .rest()
+            .starts_with(|c: char| c.is_ascii_digit() || c == '-')
+        {
+            Monkey::Number(line.integer()?)
+        } else {
+            let mut operand = |line: &mut Line<'a>| {
+                let column = line.column();
+                let name = line.word()?;
+                let error = ParseError::new(line.number(), column, "the name of a monkey")
+                    .found(format!("{name:?}"));
+                references.push((name, error));
+                Ok(name)
+            };
+
+            let n1 = operand(&mut line)?;
+            let op = line.one_of(&[
+                (" + ", Op::Plus),
+                (" - ", Op::Minus),
+                (" * ", Op::Times),
+                (" / ", Op::Divide),
+            ])?;
+            let n2 = operand(&mut line)?;
+            Monkey::Operation(op, n1, n2)
+        };
+        line.end()?;
+
+        Ok((name, monkey))
+    }
+}
+
+/// The monkeys that the root monkey depends on, as an expression tree.
+#[derive(Debug)]
+pub enum Tree {
+    Leaf { number: i64, is_human: bool },
+    Node(Op, Box<Tree>, Box<Tree>),
+}
+
+impl Tree {
+    fn build<'a>(monkeys: &HashMap<&'a str, Monkey<'a>>, root: &'a str, human: &'a str) -> Self {
+        match monkeys[root] {
+            Monkey::Number(n) => Self::Leaf {
+                number: n,
+                is_human: root == human,
+            },
+            Monkey::Operation(op, n1, n2) => Self::Node(
+                op,
+                Box::new(Self::build(monkeys, n1, human)),
+                Box::new(Self::build(monkeys, n2, human)),
+            ),
+        }
+    }
+
+    /// The number yelled by the root of the tree.
+    pub fn eval(&self) -> i64 {
+        match self {
+            Tree::Leaf { number, .. } => *number,
+            Tree::Node(op, c1, c2) => op.eval(c1.eval(), c2.eval()),
+        }
+    }
+
+    /// The number the human has to yell so that both operands of the root are equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the human does not appear in exactly one operand of the root.
+    pub fn find_root_human_number(&self) -> i64 {
+        match self {
+            Tree::Leaf { .. } => panic!("root cannot be a leaf"),
+            Tree::Node(_, c1, c2) => match (c1.eval_human(), c2.eval_human()) {
+                (Some(n1), None) => c2.find_human_number(n1).unwrap(),
+                (None, Some(n2)) => c1.find_human_number(n2).unwrap(),
+                (Some(_), Some(_)) => panic!("no branch contains the human"),
+                (None, None) => panic!("both branches contain the human"),
+            },
+        }
+    }
+
+    fn find_human_number(&self, target: i64) -> Result<i64, i64> {
+        match self {
+            Tree::Leaf {
+                number, is_human, ..
+            } => {
+                if *is_human {
+                    Ok(target)
+                } else {
+                    Err(*number)
+                }
+            }
+            Tree::Node(op, c1, c2) => match (c1.eval_human(), c2.eval_human()) {
+                (Some(n1), None) => c2.find_human_number(op.left_inverse(target, n1)),
+                (None, Some(n2)) => c1.find_human_number(op.right_inverse(target, n2)),
+                (Some(n1), Some(n2)) => Err(op.eval(n1, n2)),
+                (None, None) => panic!("both branches need the human"),
+            },
+        }
+    }
+
+    fn eval_human(&self) -> Option<i64> {
+        match self {
+            Tree::Leaf { number, is_human } => {
+                if *is_human {
+                    None
+                } else {
+                    Some(*number)
+                }
+            }
+            Tree::Node(op, c1, c2) => Some(op.eval(c1.eval_human()?, c2.eval_human()?)),
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Tree;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut references = Vec::new();
+        let monkeys: HashMap<&str, Monkey> = parse::lines(input)
+            .map(|line| Monkey::parse(line, &mut references))
+            .collect::<Result<_, _>>()?;
+
+        if let Some((_, error)) = references
+            .into_iter()
+            .find(|(name, _)| !monkeys.contains_key(name))
+        {
+            return Err(error);
+        }
+        for name in ["root", "humn"] {
+            if !monkeys.contains_key(name) {
+                let line = input.lines().count() + 1;
+                return Err(ParseError::end_of_input(
+                    line,
+                    format!("a monkey named {name:?}"),
+                ));
+            }
+        }
+
+        Ok(Tree::build(&monkeys, "root", "humn"))
+    }
+
+    fn part1(&self, tree: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(tree.eval().into())
+    }
+
+    fn part2(&self, tree: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(tree.find_root_human_number().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
+
+    #[test]
+    fn part1() {
+        let input = Day21.parse(SAMPLE).unwrap();
+        assert_eq!(Day21.part1(&input).unwrap(), 152.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day21.parse(SAMPLE).unwrap();
+        assert_eq!(Day21.part2(&input).unwrap(), Some(301.into()));
+    }
+}
@@ -0,0 +1,60 @@
+//! Random monkey maps.
+
+use rand::Rng;
+
+use crate::Day22;
+
+/// The sides of the cube on the flat board, as column and row in units of the cube size, numbered like in
+/// [`Day22::default`].
+const SIDES: [(usize, usize); 6] = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+
+/// Generates a board with the layout expected by the default [`Day22`] and a path of `size` steps.
+///
+/// About a tenth of the tiles are walls, except for the start in the top left corner.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let side = Day22::default().cube_size;
+
+    let mut output = String::new();
+    for row in 0..4 {
+        let columns: Vec<usize> = SIDES.iter().filter(|s| s.1 == row).map(|s| s.0).collect();
+        let first = columns[0];
+
+        for y in 0..side {
+            output.push_str(&" ".repeat(first * side));
+            for x in 0..columns.len() * side {
+                let is_start = row == 0 && y == 0 && x == 0;
+                let wall = !is_start && rng.gen_bool(0.1);
+                output.push(if wall { '#' } else { '.' });
+            }
+            output.push('\n');
+        }
+    }
+
+    output.push('\n');
+    for i in 0..size.max(1) {
+        if i % 2 == 0 {
+            output.push_str(&rng.gen_range(1..=side).to_string());
+        } else {
+            output.push(if rng.gen() { 'L' } else { 'R' });
+        }
+    }
+    output.push('\n');
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day22::default().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,586 @@
+//! Day 22: Monkey Map
+
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// A position as column and row, counted from 0.
+pub type Pos = (usize, usize);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn left(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Left,
+            Down => Right,
+            Left => Down,
+            Right => Up,
+        }
+    }
+
+    fn right(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Right,
+            Down => Left,
+            Left => Up,
+            Right => Down,
+        }
+    }
+
+    /// The value of the direction in the password.
+    pub fn facing(self) -> usize {
+        match self {
+            Self::Up => 3,
+            Self::Down => 1,
+            Self::Left => 2,
+            Self::Right => 0,
+        }
+    }
+
+    fn opposite(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Down,
+            Down => Up,
+            Left => Right,
+            Right => Left,
+        }
+    }
+}
+
+struct Row {
+    offset: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Row {
+    fn contains_x(&self, x: usize) -> bool {
+        x >= self.offset && x < self.offset + self.tiles.len()
+    }
+
+    fn tile(&self, x: usize) -> Option<Tile> {
+        if self.contains_x(x) {
+            Some(self.tiles[x - self.offset])
+        } else {
+            None
+        }
+    }
+}
+
+/// The flat board, where moving off an edge wraps around to the other end of the row or column.
+pub struct Board {
+    map: Vec<Row>,
+}
+
+impl Board {
+    fn new() -> Self {
+        Self { map: Vec::new() }
+    }
+
+    fn add_row(&mut self, mut line: Line) -> Result<(), ParseError> {
+        let mut offset = 0;
+        while line.eat(" ") {
+            offset += 1;
+        }
+
+        let mut tiles = Vec::new();
+        while !line.is_empty() {
+            tiles.push(line.one_of(&[(".", Tile::Open), ("#", Tile::Wall)])?);
+        }
+        if tiles.is_empty() {
+            return Err(line.error("a tile"));
+        }
+
+        self.map.push(Row { offset, tiles });
+        Ok(())
+    }
+
+    fn next_pos(&self, pos: Pos, dir: Direction) -> Pos {
+        let (mut x, mut y) = pos;
+
+        match dir {
+            Direction::Up => {
+                if y == 0 || !self.map[y - 1].contains_x(x) {
+                    // go down to find the matching row to wrap around
+                    while y < self.map.len() - 1 && self.map[y + 1].contains_x(x) {
+                        y += 1;
+                    }
+                } else {
+                    y = (self.map.len() + y - 1) % self.map.len();
+                }
+            }
+            Direction::Down => {
+                if y == self.map.len() - 1 || !self.map[y + 1].contains_x(x) {
+                    // go up to find the matching row to wrap around
+                    while y > 0 && self.map[y - 1].contains_x(x) {
+                        y -= 1;
+                    }
+                } else {
+                    y = (y + 1) % self.map.len();
+                }
+            }
+            Direction::Left => {
+                let row = &self.map[y];
+                x = if x == 0 || !row.contains_x(x - 1) {
+                    row.offset + row.tiles.len() - 1
+                } else {
+                    x - 1
+                };
+            }
+            Direction::Right => {
+                let row = &self.map[y];
+                x = if !row.contains_x(x + 1) {
+                    row.offset
+                } else {
+                    x + 1
+                };
+            }
+        };
+
+        // unwrap, because x and y should always be valid at this point
+        let tile = self.map[y].tile(x).unwrap();
+        if tile == Tile::Wall {
+            pos
+        } else {
+            (x, y)
+        }
+    }
+}
+
+/// The board folded into a cube, split into its six sides.
+pub struct CubeBoard {
+    sides: [Vec<Vec<Tile>>; 6],
+    /// The position of the top left corner of each side on the flat board.
+    origins: Vec<Pos>,
+    foldings: HashMap<(usize, Direction), (usize, Direction)>,
+}
+
+impl CubeBoard {
+    fn new(foldings: HashMap<(usize, Direction), (usize, Direction)>) -> Self {
+        Self {
+            sides: [
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            ],
+            origins: Vec::new(),
+            foldings,
+        }
+    }
+
+    fn add_row(&mut self, tiles: &[Tile], side: usize) {
+        self.sides[side - 1].push(tiles.to_vec());
+    }
+
+    fn next_pos(&self, start_side: usize, pos: Pos, dir: Direction) -> (usize, Pos, Direction) {
+        let mut side = start_side;
+        let (mut x, mut y) = pos;
+        let mut wrap = false;
+        let mut new_dir = dir;
+
+        match dir {
+            Direction::Up => {
+                if y == 0 {
+                    wrap = true;
+                } else {
+                    y -= 1;
+                }
+            }
+            Direction::Down => {
+                if y == self.sides[side - 1].len() - 1 {
+                    wrap = true;
+                } else {
+                    y += 1;
+                }
+            }
+            Direction::Left => {
+                if x == 0 {
+                    wrap = true;
+                } else {
+                    x -= 1;
+                }
+            }
+            Direction::Right => {
+                if x == self.sides[side - 1][y].len() - 1 {
+                    wrap = true;
+                } else {
+                    x += 1;
+                }
+            }
+        };
+
+        if wrap {
+            let entry_dir;
+            (side, entry_dir) = self.foldings[&(start_side, dir)];
+            (x, y) = self.side_entry(side, (x, y), dir, entry_dir);
+            new_dir = entry_dir.opposite();
+        }
+
+        // unwrap, because x and y should always be valid at this point
+        let tile = self.sides[side - 1][y][x];
+        if tile == Tile::Wall {
+            (start_side, pos, dir)
+        } else {
+            (side, (x, y), new_dir)
+        }
+    }
+
+    fn side_entry(&self, side: usize, (x, y): Pos, from_dir: Direction, to_dir: Direction) -> Pos {
+        use Direction::*;
+
+        // assume all sides are squares of the same size
+        let size = self.sides[side - 1].len() - 1;
+        match (from_dir, to_dir) {
+            (Up, Up) => (size - x, 0),
+            (Up, Down) => (x, size),
+            (Up, Left) => (0, x),
+            (Up, Right) => (size, size - x),
+            (Down, Up) => (x, 0),
+            (Down, Down) => (size - x, size),
+            (Down, Left) => (0, size - x),
+            (Down, Right) => (size, x),
+            (Left, Up) => (y, 0),
+            (Left, Down) => (size - y, size),
+            (Left, Left) => (0, size - y),
+            (Left, Right) => (size, y),
+            (Right, Up) => (size - y, 0),
+            (Right, Down) => (y, size),
+            (Right, Left) => (0, y),
+            (Right, Right) => (size, size - y),
+        }
+    }
+}
+
+/// An instruction of the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Forward(u32),
+    TurnLeft,
+    TurnRight,
+}
+
+fn parse_path(mut line: Line) -> Result<Vec<Step>, ParseError> {
+    let mut path = Vec::new();
+    while !line.is_empty() {
+        let step = if line.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            Step::Forward(line.integer()?)
+        } else {
+            line.one_of(&[("L", Step::TurnLeft), ("R", Step::TurnRight)])?
+        };
+        path.push(step);
+    }
+
+    Ok(path)
+}
+
+/// Follows the path from the leftmost open tile of the top row, returning the final position and direction.
+pub fn get_target_pos(board: &Board, path: &[Step]) -> (Pos, Direction) {
+    let mut pos = (board.map[0].offset, 0);
+    let mut dir = Direction::Right;
+
+    for &step in path {
+        match step {
+            Step::TurnRight => dir = dir.right(),
+            Step::TurnLeft => dir = dir.left(),
+            Step::Forward(n) => {
+                for _ in 0..n {
+                    pos = board.next_pos(pos, dir);
+                }
+            }
+        }
+    }
+
+    (pos, dir)
+}
+
+/// Follows the path on the cube from the top left corner of side 1, returning the final side, the position within that
+/// side and the direction.
+pub fn get_cube_target_pos(board: &CubeBoard, path: &[Step]) -> (usize, Pos, Direction) {
+    let mut side = 1;
+    let mut pos = (0, 0);
+    let mut dir = Direction::Right;
+
+    for &step in path {
+        match step {
+            Step::TurnRight => dir = dir.right(),
+            Step::TurnLeft => dir = dir.left(),
+            Step::Forward(n) => {
+                for _ in 0..n {
+                    (side, pos, dir) = board.next_pos(side, pos, dir);
+                }
+            }
+        }
+    }
+
+    (side, pos, dir)
+}
+
+/// The board (both flat and folded into a cube) and the path to follow on it.
+pub struct Notes {
+    board: Board,
+    cube_board: CubeBoard,
+    path: Vec<Step>,
+}
+
+impl Notes {
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn cube_board(&self) -> &CubeBoard {
+        &self.cube_board
+    }
+
+    pub fn path(&self) -> &[Step] {
+        &self.path
+    }
+}
+
+/// A folding maps the edge of a side (sides are numbered from 1 in reading order of the flat board) to the edge of the
+/// side it is connected to on the cube.
+pub type Folding = ((usize, Direction), (usize, Direction));
+
+/// Reports the final position (starting at 1) and facing of a part as extras.
+fn add_position(row: usize, column: usize, dir: Direction) {
+    common::extras::add("row", row);
+    common::extras::add("column", column);
+    common::extras::add("facing", dir.facing());
+}
+
+pub struct Day22 {
+    /// The edge length of a cube side.
+    pub cube_size: usize,
+    /// How the sides of the flat board are connected when folded into a cube.
+    pub foldings: Vec<Folding>,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        use Direction::*;
+        Self {
+            cube_size: 50,
+            foldings: vec![
+                ((1, Up), (6, Left)),
+                ((1, Down), (3, Up)),
+                ((1, Left), (4, Left)),
+                ((1, Right), (2, Left)),
+                //
+                ((2, Up), (6, Down)),
+                ((2, Down), (3, Right)),
+                ((2, Left), (1, Right)),
+                ((2, Right), (5, Right)),
+                //
+                ((3, Up), (1, Down)),
+                ((3, Down), (5, Up)),
+                ((3, Left), (4, Up)),
+                ((3, Right), (2, Down)),
+                //
+                ((4, Up), (3, Left)),
+                ((4, Down), (6, Up)),
+                ((4, Left), (1, Left)),
+                ((4, Right), (5, Left)),
+                //
+                ((5, Up), (3, Down)),
+                ((5, Down), (6, Right)),
+                ((5, Left), (4, Right)),
+                ((5, Right), (2, Right)),
+                //
+                ((6, Up), (4, Down)),
+                ((6, Down), (2, Up)),
+                ((6, Left), (1, Up)),
+                ((6, Right), (5, Down)),
+            ],
+        }
+    }
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut board = Board::new();
+        let mut lines = parse::lines(input);
+        for line in &mut lines {
+            if line.is_empty() {
+                break; // next line will be the path
+            }
+
+            board.add_row(line)?;
+        }
+        if board.map.is_empty() {
+            return Err(ParseError::new(1, 1, "a row of the map"));
+        }
+
+        let rows = board.map.len();
+        let path = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(rows + 2, "a path"))?;
+        let path = parse_path(path)?;
+        if let Some(line) = lines.next() {
+            return Err(line.error("end of input"));
+        }
+
+        let mut cube_board = CubeBoard::new(self.foldings.iter().copied().collect());
+
+        let size = self.cube_size;
+        let mut side = 1;
+        let mut sides = 0;
+        for (y, row) in board.map.iter().enumerate() {
+            if y.is_multiple_of(size) {
+                if !row.tiles.len().is_multiple_of(size) {
+                    let column = row.offset + row.tiles.len() + 1;
+                    let expected = format!("a row of cube sides of width {size}");
+                    return Err(ParseError::new(y + 1, column, expected));
+                }
+
+                side += sides;
+                sides = row.tiles.len() / size;
+                if side + sides > 7 {
+                    return Err(
+                        ParseError::new(y + 1, row.offset + 1, "at most 6 cube sides")
+                            .found(format!("{} cube sides", side + sides - 1)),
+                    );
+                }
+
+                cube_board
+                    .origins
+                    .extend((0..sides).map(|i| (row.offset + i * size, y)));
+            } else {
+                let first = &board.map[y - y % size];
+                if (row.offset, row.tiles.len()) != (first.offset, first.tiles.len()) {
+                    let expected =
+                        format!("{sides} cube sides starting at column {}", first.offset + 1);
+                    return Err(ParseError::new(y + 1, row.offset + 1, expected));
+                }
+            }
+
+            for i in 0..sides {
+                cube_board.add_row(&row.tiles[i * size..(i + 1) * size], side + i);
+            }
+        }
+
+        if !rows.is_multiple_of(size) || side + sides != 7 {
+            let expected = format!("{size} rows per cube side and 6 sides in total");
+            return Err(ParseError::new(rows + 1, 1, expected).found("the end of the map"));
+        }
+
+        Ok(Notes {
+            board,
+            cube_board,
+            path,
+        })
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Result<Answer, SolveError> {
+        let ((column, row), dir) = get_target_pos(&notes.board, &notes.path);
+        add_position(row + 1, column + 1, dir);
+
+        Ok((1000 * (row + 1) + 4 * (column + 1) + dir.facing()).into())
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let (side, (column, row), dir) = get_cube_target_pos(&notes.cube_board, &notes.path);
+        let origin = notes.cube_board.origins[side - 1];
+        let row = origin.1 + row + 1;
+        let column = origin.0 + column + 1;
+        add_position(row, column, dir);
+
+        Ok(Some((1000 * row + 4 * column + dir.facing()).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day() -> Day22 {
+        use Direction::*;
+        Day22 {
+            cube_size: 4,
+            foldings: vec![
+                ((1, Up), (2, Up)),
+                ((1, Down), (4, Up)),
+                ((1, Left), (3, Up)),
+                ((1, Right), (6, Right)),
+                //
+                ((2, Up), (1, Up)),
+                ((2, Down), (5, Down)),
+                ((2, Left), (6, Down)),
+                ((2, Right), (3, Left)),
+                //
+                ((3, Up), (1, Left)),
+                ((3, Down), (5, Left)),
+                ((3, Left), (2, Right)),
+                ((3, Right), (4, Left)),
+                //
+                ((4, Up), (1, Down)),
+                ((4, Down), (5, Up)),
+                ((4, Left), (3, Right)),
+                ((4, Right), (6, Up)),
+                //
+                ((5, Up), (4, Down)),
+                ((5, Down), (2, Down)),
+                ((5, Left), (3, Down)),
+                ((5, Right), (6, Left)),
+                //
+                ((6, Up), (4, Right)),
+                ((6, Down), (2, Left)),
+                ((6, Left), (5, Right)),
+                ((6, Right), (1, Right)),
+            ],
+        }
+    }
+
+    const SAMPLE: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
+    #[test]
+    fn part1() {
+        let input = day().parse(SAMPLE).unwrap();
+        assert_eq!(day().part1(&input).unwrap(), 6032.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = day().parse(SAMPLE).unwrap();
+        assert_eq!(day().part2(&input).unwrap(), Some(5031.into()));
+    }
+}
@@ -0,0 +1,39 @@
+//! Random elf positions.
+
+use rand::Rng;
+
+/// Generates a square scan with `size` rows and columns, in which about a quarter of the positions hold an elf.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut rows: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.25) { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+
+    // the empty ground is measured around the elves, so there must be at least one
+    rows[size / 2][size / 2] = b'#';
+
+    rows.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day23;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day23.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
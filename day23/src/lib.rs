@@ -0,0 +1,170 @@
+//! Day 23: Unstable Diffusion
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use common::{parse::ParseError, Answer, Solution, SolveError};
+use grid::{Grid, Point, SparseGrid};
+use visualize::Frame;
+
+pub mod generate;
+
+/// The positions of the elves.
+pub type Elves = SparseGrid<()>;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    fn offsets(&self, Point { x, y }: Point) -> [Point; 3] {
+        match self {
+            Direction::North => [(x - 1, y - 1), (x, y - 1), (x + 1, y - 1)],
+            Direction::South => [(x - 1, y + 1), (x, y + 1), (x + 1, y + 1)],
+            Direction::West => [(x - 1, y - 1), (x - 1, y), (x - 1, y + 1)],
+            Direction::East => [(x + 1, y - 1), (x + 1, y), (x + 1, y + 1)],
+        }
+        .map(Point::from)
+    }
+
+    fn shift(&self, Point { x, y }: Point) -> Point {
+        match self {
+            Direction::North => Point::new(x, y - 1),
+            Direction::South => Point::new(x, y + 1),
+            Direction::West => Point::new(x - 1, y),
+            Direction::East => Point::new(x + 1, y),
+        }
+    }
+}
+
+fn spread(elves: &mut Elves, directions: &[Direction]) -> bool {
+    let mut proposed = HashMap::new();
+
+    for pos in elves.points() {
+        if elves.neighbors8(pos).next().is_none() {
+            // the elf contains no neighbors, so doesn’t move at all
+            continue;
+        }
+
+        for &d in directions {
+            if d.offsets(pos)
+                .iter()
+                .all(|&check_pos| !elves.contains(check_pos))
+            {
+                let target_pos = d.shift(pos);
+                match proposed.entry(target_pos) {
+                    Entry::Occupied(mut e) => {
+                        // mark the position as proposed by multiple elves
+                        e.insert(None);
+                    }
+                    Entry::Vacant(e) => {
+                        e.insert(Some(pos));
+                    }
+                }
+
+                break;
+            }
+        }
+    }
+
+    let mut changed = false;
+    for (target_pos, source_pos) in proposed {
+        if let Some(source_pos) = source_pos {
+            elves.remove(source_pos);
+            elves.insert(target_pos, ());
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+fn render(elves: &Elves) -> String {
+    elves.render(|c| if c.is_some() { '#' } else { '.' })
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::West,
+    Direction::East,
+];
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Elves;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        SparseGrid::parse(input, |p, b| match b {
+            b'#' => Ok(Some(())),
+            b'.' => Ok(None),
+            _ => Err(grid::unexpected_cell(p, b, "'#' or '.'")),
+        })
+    }
+
+    fn part1(&self, elves: &Self::Input) -> Result<Answer, SolveError> {
+        let mut elves = elves.clone();
+        tracing::trace!("initial elves:\n{}", render(&elves));
+
+        visualize::emit(|| Frame::from_text(&render(&elves)));
+
+        let mut directions = DIRECTIONS;
+        for _ in 0..10 {
+            spread(&mut elves, &directions);
+            directions.rotate_left(1);
+            visualize::emit(|| Frame::from_text(&render(&elves)));
+        }
+
+        let bounds = elves.bounds().expect("no elves");
+        Ok((bounds.area() - elves.len() as i64).into())
+    }
+
+    fn part2(&self, elves: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let mut elves = elves.clone();
+
+        visualize::emit(|| Frame::from_text(&render(&elves)));
+
+        let mut directions = DIRECTIONS;
+        let mut cnt = 0;
+        while spread(&mut elves, &directions) {
+            directions.rotate_left(1);
+            cnt += 1;
+            visualize::emit(|| Frame::from_text(&render(&elves)));
+        }
+
+        Ok(Some((cnt + 1).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..
+";
+
+    #[test]
+    fn part1() {
+        let input = Day23.parse(SAMPLE).unwrap();
+        assert_eq!(Day23.part1(&input).unwrap(), 110.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day23.parse(SAMPLE).unwrap();
+        assert_eq!(Day23.part2(&input).unwrap(), Some(20.into()));
+    }
+}
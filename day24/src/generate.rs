@@ -0,0 +1,49 @@
+//! Random valleys.
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Generates a valley that is `size` positions wide (rounded down to a multiple of 4, at least 8) and a quarter as
+/// high, with a blizzard on about a third of its positions.
+///
+/// The height divides the width, so that the blizzards repeat after as many minutes as the valley is wide. As in the
+/// puzzle input, no blizzard moves vertically in the columns of the entrance and the exit.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let height = (size / 4).max(2);
+    let width = height * 4;
+
+    let mut output = format!("#.{}\n", "#".repeat(width));
+    for _ in 0..height {
+        output.push('#');
+        for x in 0..width {
+            let blizzard = if !rng.gen_bool(0.3) {
+                '.'
+            } else if x == 0 || x == width - 1 {
+                *['<', '>'].choose(rng).unwrap()
+            } else {
+                *['<', '>', '^', 'v'].choose(rng).unwrap()
+            };
+            output.push(blizzard);
+        }
+        output.push_str("#\n");
+    }
+    output.push_str(&format!("{}.#\n", "#".repeat(width)));
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day24;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day24.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Day 24: Blizzard Basin
+
+use std::collections::HashMap;
+
+use common::{parse::ParseError, Answer, Solution, SolveError};
+use grid::{Bounds, DenseGrid, Direction, Grid, Point};
+use num::Integer;
+use visualize::Frame;
+
+pub mod generate;
+
+fn neighbors_or_self(grid: &DenseGrid<bool>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+    std::iter::once(pos).chain(grid.neighbors4(pos))
+}
+
+fn parse_direction(b: u8) -> Option<Direction> {
+    match b {
+        b'^' => Some(Direction::Up),
+        b'v' => Some(Direction::Down),
+        b'<' => Some(Direction::Left),
+        b'>' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+/// The blizzards of the valley and the size of the area inside its walls.
+pub struct Valley {
+    /// The initial positions of the blizzards, relative to the top left corner inside the walls.
+    blizzards: Vec<(Point, Direction)>,
+    width: i32,
+    height: i32,
+}
+
+impl Valley {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut blizzards = Vec::new();
+        let map = DenseGrid::parse(input, |p, b| {
+            if let Some(d) = parse_direction(b) {
+                blizzards.push((p - Point::new(1, 1), d));
+            } else if b != b'#' && b != b'.' {
+                return Err(grid::unexpected_cell(
+                    p,
+                    b,
+                    "a wall, open ground or a blizzard",
+                ));
+            }
+            Ok(())
+        })?;
+
+        if map.height() < 3 {
+            let line = map.height() as usize + 1;
+            return Err(ParseError::end_of_input(line, "a row of the valley"));
+        }
+        if map.width() < 3 {
+            return Err(ParseError::new(
+                1,
+                map.width() as usize + 1,
+                "a valley of width 3 or more",
+            ));
+        }
+
+        Ok(Self {
+            blizzards,
+            width: map.width() - 2,
+            height: map.height() - 2,
+        })
+    }
+
+    /// The width of the area inside the walls.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// The height of the area inside the walls.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Returns which positions are occupied by a blizzard at time `t`.
+    fn occupied_map(&self, t: i32) -> DenseGrid<bool> {
+        let mut result = DenseGrid::new(self.width, self.height, false);
+        for &(pos, d) in &self.blizzards {
+            let pos = result.wrap(pos + d.offset() * t);
+            result[pos] = true;
+        }
+
+        result
+    }
+
+    /// Renders the valley at time `t` like the puzzle description, with the expedition at `expedition` (relative to
+    /// the top left corner inside the walls, like the blizzards).
+    fn render(&self, t: i32, expedition: Point) -> Frame {
+        let bounds = Bounds::from_size(self.width, self.height);
+        let mut blizzards = HashMap::new();
+        for &(pos, d) in &self.blizzards {
+            let (count, _) = blizzards
+                .entry(bounds.wrap(pos + d.offset() * t))
+                .or_insert((0, d));
+            *count += 1;
+        }
+
+        let entrance = Point::new(0, -1);
+        let exit = Point::new(self.width - 1, self.height);
+        let (width, height) = (self.width as usize + 2, self.height as usize + 2);
+        Frame::from_fn(width, height, |x, y| {
+            let p = Point::new(x as i32 - 1, y as i32 - 1);
+            if p == expedition {
+                'E'
+            } else if p == entrance || p == exit {
+                '.'
+            } else if !bounds.contains(p) {
+                '#'
+            } else {
+                match blizzards.get(&p) {
+                    None => '.',
+                    Some((1, Direction::Up)) => '^',
+                    Some((1, Direction::Down)) => 'v',
+                    Some((1, Direction::Left)) => '<',
+                    Some((1, Direction::Right)) => '>',
+                    Some((count, _)) => char::from_digit(*count, 10).unwrap_or('*'),
+                }
+            }
+        })
+    }
+}
+
+/// Returns how many minutes it takes to get from `start_pos` to `end_pos` (and out of the valley), starting at
+/// `start_time` outside of the valley next to `start_pos`.
+pub fn shortest_path(valley: &Valley, start_time: i32, start_pos: Point, end_pos: Point) -> i32 {
+    // the blizzards repeat after len_t minutes, so the time only matters modulo len_t
+    let len_t = valley.width.lcm(&valley.height);
+    let occupied: Vec<_> = (0..len_t)
+        .map(|t| valley.occupied_map(start_time + t))
+        .collect();
+    let is_free = |pos: Point, t: i32| !occupied[t as usize][pos];
+
+    // a position of `None` means waiting outside of the valley
+    let neighbors = |&(pos, t): &(Option<Point>, i32)| {
+        let next_t = (t + 1) % len_t;
+        let next: Vec<Option<Point>> = match pos {
+            None => vec![None, Some(start_pos)],
+            Some(pos) => neighbors_or_self(&occupied[0], pos).map(Some).collect(),
+        };
+        next.into_iter()
+            .filter(move |next| next.is_none_or(|p| is_free(p, next_t)))
+            .map(move |next| (next, next_t))
+    };
+
+    let mut starts = vec![(None, 0)];
+    if is_free(start_pos, 0) {
+        starts.push((Some(start_pos), 0));
+    }
+
+    let search = search::bfs(starts, neighbors, |&(pos, _)| pos == Some(end_pos));
+
+    if visualize::is_enabled() {
+        // waiting outside of the valley happens on the wall next to the start position
+        let outside = if start_pos.y == 0 {
+            start_pos + Direction::Up.offset()
+        } else {
+            start_pos + Direction::Down.offset()
+        };
+
+        for (elapsed, (pos, _)) in search.path().into_iter().flatten().enumerate() {
+            let t = start_time + elapsed as i32;
+            visualize::emit(|| valley.render(t, pos.unwrap_or(outside)));
+        }
+    }
+
+    search.cost().expect("no path found") as i32 + 1
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Valley;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Valley::parse(input)
+    }
+
+    fn part1(&self, valley: &Self::Input) -> Result<Answer, SolveError> {
+        let start = Point::ORIGIN;
+        let end = Point::new(valley.width - 1, valley.height - 1);
+
+        let length = shortest_path(valley, 0, start, end);
+        common::extras::add("leg1", length);
+        Ok(length.into())
+    }
+
+    fn part2(&self, valley: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let start = Point::ORIGIN;
+        let end = Point::new(valley.width - 1, valley.height - 1);
+
+        let l1 = shortest_path(valley, 0, start, end);
+        let l2 = shortest_path(valley, l1, end, start);
+        let l3 = shortest_path(valley, l1 + l2, start, end);
+        common::extras::add("leg1", l1);
+        common::extras::add("leg2", l2);
+        common::extras::add("leg3", l3);
+        Ok(Some((l1 + l2 + l3).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#
+";
+
+    #[test]
+    fn part1() {
+        let input = Day24.parse(SAMPLE).unwrap();
+        assert_eq!(Day24.part1(&input).unwrap(), 18.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day24.parse(SAMPLE).unwrap();
+        assert_eq!(Day24.part2(&input).unwrap(), Some(54.into()));
+    }
+}
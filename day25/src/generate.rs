@@ -0,0 +1,29 @@
+//! Random fuel requirements.
+
+use rand::Rng;
+
+use crate::to_snafu;
+
+/// Generates `size` SNAFU numbers of up to 18 digits.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| to_snafu(rng.gen_range(1..=1_000_000_000_000)) + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day25;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day25.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Day 25: Full of Hot Air
+//!
+//! SNAFU numbers are written in base 5 with the digits `=` (-2), `-` (-1), `0`, `1` and `2`, see [`from_snafu`] and
+//! [`to_snafu`].
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// Parses a SNAFU number that spans the rest of `line`.
+fn parse_snafu(line: &mut Line) -> Result<i64, ParseError> {
+    let mut result = 0;
+    loop {
+        let digit = line.one_of(&[("0", 0), ("1", 1), ("2", 2), ("-", -1), ("=", -2)])?;
+        result = result * 5 + digit;
+
+        if line.is_empty() {
+            return Ok(result);
+        }
+    }
+}
+
+/// Converts a SNAFU number to decimal.
+///
+/// ```
+/// assert_eq!(day25::from_snafu("1=-0-2"), Ok(1747));
+/// assert!(day25::from_snafu("1=3").is_err());
+/// ```
+pub fn from_snafu(snafu: &str) -> Result<i64, ParseError> {
+    parse_snafu(&mut Line::new(1, snafu))
+}
+
+/// Converts a non-negative number to SNAFU.
+///
+/// ```
+/// assert_eq!(day25::to_snafu(1747), "1=-0-2");
+/// ```
+pub fn to_snafu(mut n: i64) -> String {
+    if n == 0 {
+        return "0".to_owned();
+    }
+
+    let mut result = "".to_owned();
+    while n > 0 {
+        let digit = n % 5;
+        if digit == 3 {
+            result += "=";
+            n += 2;
+        } else if digit == 4 {
+            result += "-";
+            n += 1;
+        } else {
+            result += &digit.to_string();
+        }
+
+        n /= 5;
+    }
+
+    result.chars().rev().collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|mut line| parse_snafu(&mut line))
+            .collect()
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(to_snafu(numbers.iter().sum()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122
+";
+
+    #[test]
+    fn part1() {
+        let input = Day25.parse(SAMPLE).unwrap();
+        assert_eq!(Day25.part1(&input).unwrap(), "2=-1=0".into());
+    }
+
+    #[test]
+    fn unexpected_digit() {
+        let error = Day25.parse("1=-0\n12311\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected \"0\" or \"1\" or \"2\" or \"-\" or \"=\", found \"311\""
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Random rucksack contents.
+
+use rand::{seq::SliceRandom, Rng};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generates `size` rucksacks (rounded up to whole groups of three).
+///
+/// The compartments of each rucksack share exactly one item type and the rucksacks of each group share exactly one
+/// badge.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1).div_ceil(3) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let badge = items.pop().unwrap();
+
+        // the three elves of the group use disjoint item types apart from the badge
+        for types in items.chunks(items.len() / 3) {
+            output.push_str(&rucksack(rng, types, badge));
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Generates a rucksack with items of `types` and `badge`.
+fn rucksack(rng: &mut impl Rng, types: &[u8], badge: u8) -> String {
+    let shared = if rng.gen_bool(0.2) { badge } else { types[0] };
+    let half = types.len() / 2;
+    let mut left_types = types[1..half].to_vec();
+    let mut right_types = types[half..].to_vec();
+    if shared != badge {
+        if rng.gen() {
+            left_types.push(badge);
+        } else {
+            right_types.push(badge);
+        }
+    }
+
+    let len = rng.gen_range(4..=16);
+    let mut compartment = |types: &[u8]| {
+        let mut items = vec![shared];
+        if types.contains(&badge) {
+            items.push(badge);
+        }
+        while items.len() < len {
+            items.push(*types.choose(rng).unwrap());
+        }
+        items.shuffle(rng);
+        items
+    };
+
+    let mut items = compartment(&left_types);
+    items.extend(compartment(&right_types));
+    String::from_utf8(items).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day3;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day3::default().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
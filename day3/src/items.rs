@@ -0,0 +1,141 @@
+//! Sets of item types, stored as bit masks with one bit per priority.
+//!
+//! ```
+//! use day3::items::ItemSet;
+//!
+//! let left = ItemSet::from_items(b"vJrwpWtwJgWr");
+//! let right = ItemSet::from_items(b"hcsFMMfFFhFp");
+//! let shared = left & right;
+//! assert_eq!(shared.items().collect::<Vec<_>>(), b"p");
+//! assert_eq!(shared.priorities(), 16);
+//! ```
+
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, Sub},
+};
+
+use crate::{item, priority};
+
+/// A set of item types, where the item type with priority `p` is stored in bit `p - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: Self = Self(0);
+    /// All 52 item types.
+    pub const ALL: Self = Self((1 << 52) - 1);
+
+    /// The set of item types in `items`, ignoring bytes that are not item types.
+    pub fn from_items(items: &[u8]) -> Self {
+        items.iter().copied().collect()
+    }
+
+    pub fn contains(self, item: u8) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << (p - 1)) != 0)
+    }
+
+    /// Adds `item` to the set. Returns `false` if the byte is not an item type.
+    pub fn insert(&mut self, item: u8) -> bool {
+        let Some(p) = priority(item) else {
+            return false;
+        };
+
+        self.0 |= 1 << (p - 1);
+        true
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item types in the set, in the order of their priorities.
+    pub fn items(self) -> impl Iterator<Item = u8> {
+        (1..=52)
+            .filter(move |p| self.0 & (1 << (p - 1)) != 0)
+            .filter_map(item)
+    }
+
+    /// The sum of the priorities of the item types in the set.
+    pub fn priorities(self) -> u32 {
+        self.items().filter_map(priority).sum()
+    }
+}
+
+impl FromIterator<u8> for ItemSet {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        for item in iter {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    /// The intersection of two sets.
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    /// The union of two sets.
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = Self;
+
+    /// The item types of `self` that are not in `other`.
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl fmt::Display for ItemSet {
+    /// The item types as a string, e.g. `pL`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.items() {
+            write!(f, "{}", item as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_item_type_has_its_own_bit() {
+        let all = ItemSet::from_items(b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        assert_eq!(all, ItemSet::ALL);
+        assert_eq!(all.len(), 52);
+        assert_eq!(all.priorities(), (1..=52).sum());
+        assert!(!all.contains(b'1'));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = ItemSet::from_items(b"abcZ");
+        let b = ItemSet::from_items(b"bZz!");
+        assert_eq!((a & b).to_string(), "bZ");
+        assert_eq!((a | b).to_string(), "abczZ");
+        assert_eq!((a - b).to_string(), "ac");
+        assert!((a - a).is_empty());
+        assert_eq!(b.len(), 3);
+    }
+}
@@ -0,0 +1,356 @@
+//! Day 3: Rucksack Reorganization
+
+use std::fmt::{self, Write};
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use items::ItemSet;
+use repack::{Move, NoPlan, Plan};
+
+pub mod generate;
+pub mod items;
+pub mod repack;
+
+/// The priority of an item type (1-26 for `a`-`z`, 27-52 for `A`-`Z`), or `None` if the byte is not an item type.
+pub fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some((item - b'a' + 1) as u32),
+        b'A'..=b'Z' => Some((item - b'A' + 27) as u32),
+        _ => None,
+    }
+}
+
+/// The item type with the given priority, or `None` if there is none.
+pub fn item(priority: u32) -> Option<u8> {
+    match priority {
+        1..=26 => Some(b'a' + (priority - 1) as u8),
+        27..=52 => Some(b'A' + (priority - 27) as u8),
+        _ => None,
+    }
+}
+
+/// The item types in the two compartments of a rucksack. For an odd number of items, the second compartment gets the
+/// extra item.
+pub fn compartments(rucksack: &[u8]) -> (ItemSet, ItemSet) {
+    let (c1, c2) = rucksack.split_at(rucksack.len() / 2);
+    (ItemSet::from_items(c1), ItemSet::from_items(c2))
+}
+
+/// The item types that are in both compartments of a rucksack.
+pub fn shared_items(rucksack: &[u8]) -> ItemSet {
+    let (c1, c2) = compartments(rucksack);
+    c1 & c2
+}
+
+/// The item types that are in all rucksacks of a group.
+pub fn badges<R: AsRef<[u8]>>(group: &[R]) -> ItemSet {
+    group
+        .iter()
+        .map(|rucksack| ItemSet::from_items(rucksack.as_ref()))
+        .reduce(|a, b| a & b)
+        .unwrap_or_default()
+}
+
+/// Something unexpected about the rucksacks, which the answers may not account for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// The rucksack on the given line cannot be split into two compartments of equal size.
+    OddLength { line: usize, len: usize },
+    /// The compartments of the rucksack on the given line share no or several item types.
+    SharedItems { line: usize, items: ItemSet },
+    /// The rucksacks of the group starting on the given line share no or several item types.
+    Badges { line: usize, items: ItemSet },
+    /// The last group starting on the given line has fewer rucksacks than the others.
+    IncompleteGroup { line: usize, rucksacks: usize },
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |items: &ItemSet| match items.len() {
+            0 => "no item types".to_string(),
+            n => format!("{n} item types ({items})"),
+        };
+
+        match self {
+            Self::OddLength { line, len } => {
+                write!(
+                    f,
+                    "line {line}: rucksack has an odd number of items ({len})"
+                )
+            }
+            Self::SharedItems { line, items } => {
+                write!(f, "line {line}: compartments share {}", count(items))
+            }
+            Self::Badges { line, items } => {
+                write!(f, "line {line}: group shares {}", count(items))
+            }
+            Self::IncompleteGroup { line, rucksacks } => {
+                write!(
+                    f,
+                    "line {line}: last group has only {rucksacks} rucksack(s)"
+                )
+            }
+        }
+    }
+}
+
+/// Sums the priorities of all item types shared by the compartments of each rucksack and by the rucksacks of each
+/// group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day3 {
+    group_size: usize,
+}
+
+impl Day3 {
+    /// # Panics
+    ///
+    /// Panics if `group_size` is 0.
+    pub fn new(group_size: usize) -> Self {
+        assert!(group_size > 0, "a group needs at least one elf");
+        Self { group_size }
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// The moves that make the compartments of each rucksack disjoint and give each group exactly one badge, see
+    /// [`repack`].
+    pub fn plan(&self, rucksacks: &[Vec<u8>]) -> Result<Plan, NoPlan> {
+        Plan::new(rucksacks, self.group_size)
+    }
+
+    /// The diagnostics of each rucksack, followed by the diagnostics of each group.
+    pub fn diagnostics(&self, rucksacks: &[Vec<u8>]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            diagnostics.extend(rucksack_diagnostics(i + 1, rucksack));
+        }
+        for (i, group) in rucksacks.chunks(self.group_size).enumerate() {
+            diagnostics.extend(self.group_diagnostics(i * self.group_size + 1, group));
+        }
+
+        diagnostics
+    }
+
+    fn group_diagnostics(&self, line: usize, group: &[Vec<u8>]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if group.len() < self.group_size {
+            diagnostics.push(Diagnostic::IncompleteGroup {
+                line,
+                rucksacks: group.len(),
+            });
+        }
+
+        let items = badges(group);
+        if items.len() != 1 {
+            diagnostics.push(Diagnostic::Badges { line, items });
+        }
+
+        diagnostics
+    }
+}
+
+impl Default for Day3 {
+    /// Groups of three elves, as in the puzzle.
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+fn rucksack_diagnostics(line: usize, rucksack: &[u8]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if rucksack.len() % 2 == 1 {
+        diagnostics.push(Diagnostic::OddLength {
+            line,
+            len: rucksack.len(),
+        });
+    }
+
+    let items = shared_items(rucksack);
+    if items.len() != 1 {
+        diagnostics.push(Diagnostic::SharedItems { line, items });
+    }
+
+    diagnostics
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let items = line.rest().as_bytes();
+                if let Some(i) = items.iter().position(|&b| priority(b).is_none()) {
+                    return Err(ParseError::unexpected(
+                        line.number(),
+                        line.column() + i,
+                        items[i] as char,
+                        "an item (a-z or A-Z)",
+                    ));
+                }
+
+                Ok(items.to_vec())
+            })
+            .collect()
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            for diagnostic in rucksack_diagnostics(i + 1, rucksack) {
+                tracing::warn!("{diagnostic}");
+            }
+
+            sum += shared_items(rucksack).priorities();
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let mut sum = 0;
+        for (i, group) in rucksacks.chunks(self.group_size).enumerate() {
+            for diagnostic in self.group_diagnostics(i * self.group_size + 1, group) {
+                tracing::warn!("{diagnostic}");
+            }
+
+            sum += badges(group).priorities();
+        }
+
+        Ok(Some(sum.into()))
+    }
+
+    /// The repacking plan, with the moves grouped by the rucksack they take items from, and the answers afterwards.
+    fn analysis(&self, rucksacks: &Self::Input) -> Option<String> {
+        let plan = match self.plan(rucksacks) {
+            Ok(plan) => plan,
+            Err(e) => return Some(format!("{e}\n")),
+        };
+
+        let mut report = String::new();
+        for rucksack in 0..rucksacks.len() {
+            let moves: Vec<String> = plan.moves_from(rucksack).map(Move::to_string).collect();
+            if !moves.is_empty() {
+                writeln!(report, "line {}:", rucksack + 1).unwrap();
+                for m in moves {
+                    writeln!(report, "  {m}").unwrap();
+                }
+            }
+        }
+        writeln!(report, "moved items: {}", plan.moved_items()).unwrap();
+        writeln!(
+            report,
+            "part 1 after repacking: {}",
+            plan.shared_priorities()
+        )
+        .unwrap();
+        writeln!(
+            report,
+            "part 2 after repacking: {}",
+            plan.badge_priorities()
+        )
+        .unwrap();
+
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn part1() {
+        let input = Day3::default().parse(SAMPLE).unwrap();
+        assert_eq!(Day3::default().part1(&input).unwrap(), 157.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day3::default().parse(SAMPLE).unwrap();
+        assert_eq!(Day3::default().part2(&input).unwrap(), Some(70.into()));
+    }
+
+    #[test]
+    fn analysis() {
+        let day = Day3::new(2);
+        let input = day.parse("abcd\nefgg\n").unwrap();
+        assert_eq!(
+            Solution::analysis(&day, &input).unwrap(),
+            "line 2:\n  \
+             1 × g: line 2 compartment 2 → line 1 compartment 1\n\
+             moved items: 1\n\
+             part 1 after repacking: 0\n\
+             part 2 after repacking: 7\n"
+        );
+
+        let input = day.parse("ab\ncd\n").unwrap();
+        assert_eq!(
+            Solution::analysis(&day, &input).unwrap(),
+            "line 1: the group cannot be repacked to share exactly one item type\n"
+        );
+    }
+
+    #[test]
+    fn item_is_the_inverse_of_priority() {
+        for p in 1..=52 {
+            assert_eq!(item(p).and_then(priority), Some(p));
+        }
+        assert_eq!(item(0), None);
+        assert_eq!(item(53), None);
+    }
+
+    #[test]
+    fn other_group_sizes() {
+        let day = Day3::new(2);
+        let input = day.parse(SAMPLE).unwrap();
+        // pairs of rucksacks share several item types, but each compartment still shares exactly one
+        let shared = [&b"rsfFM"[..], b"qvwBT", b"JGZ"].map(ItemSet::from_items);
+        let expected: u32 = shared.iter().map(|items| items.priorities()).sum();
+        assert_eq!(day.part2(&input).unwrap(), Some(expected.into()));
+        assert_eq!(
+            day.diagnostics(&input),
+            [1, 3, 5].map(|line| Diagnostic::Badges {
+                line,
+                items: shared[line / 2]
+            })
+        );
+    }
+
+    #[test]
+    fn diagnostics() {
+        let rucksacks = vec![b"abcab".to_vec(), b"abcd".to_vec(), b"aBBa".to_vec()];
+        let diagnostics: Vec<_> = Day3::new(2)
+            .diagnostics(&rucksacks)
+            .iter()
+            .map(Diagnostic::to_string)
+            .collect();
+        assert_eq!(
+            diagnostics,
+            [
+                "line 1: rucksack has an odd number of items (5)",
+                "line 1: compartments share 2 item types (ab)",
+                "line 2: compartments share no item types",
+                "line 3: compartments share 2 item types (aB)",
+                "line 1: group shares 3 item types (abc)",
+                "line 3: last group has only 1 rucksack(s)",
+                "line 3: group shares 2 item types (aB)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,514 @@
+//! Repacking the rucksacks with as few item moves as possible, so that the compartments of each rucksack share no item
+//! type and the rucksacks of each group share exactly one.
+//!
+//! Items only move within a group, either to the other compartment of the same rucksack or into another rucksack of
+//! the group, so the compartments may end up with different sizes.
+//!
+//! ```
+//! use day3::repack::Plan;
+//!
+//! // the rucksacks share a and b, and the cheapest way to fix this is moving the b of the second rucksack
+//! let plan = Plan::new(&[b"aabb".to_vec(), b"abcd".to_vec()], 2).unwrap();
+//! assert_eq!(plan.to_string(), "1 × b: line 2 compartment 1 → line 1 compartment 2\n");
+//! assert_eq!(plan.badge_priorities(), 1);
+//! ```
+
+use std::{error::Error, fmt, ops::Range};
+
+use crate::{item, items::ItemSet, priority};
+
+/// The number of items of each type in the two compartments of a rucksack, indexed by priority - 1.
+type Counts = [[usize; 52]; 2];
+
+/// One of the two compartments of a rucksack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Place {
+    /// The index of the rucksack, starting at 0.
+    pub rucksack: usize,
+    /// 0 for the first and 1 for the second compartment.
+    pub compartment: usize,
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {} compartment {}",
+            self.rucksack + 1,
+            self.compartment + 1
+        )
+    }
+}
+
+/// Moving `count` items of the same type from one compartment to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: u8,
+    pub count: usize,
+    pub from: Place,
+    pub to: Place,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            item,
+            count,
+            from,
+            to,
+        } = self;
+        write!(f, "{count} × {}: {from} → {to}", *item as char)
+    }
+}
+
+/// A group that cannot share exactly one item type, because it has a single rucksack with several item types or
+/// because no item type has enough items to put one into every rucksack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoPlan {
+    /// The line of the first rucksack of the group.
+    pub line: usize,
+}
+
+impl fmt::Display for NoPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: the group cannot be repacked to share exactly one item type",
+            self.line
+        )
+    }
+}
+
+impl Error for NoPlan {}
+
+/// The moves of a minimal repacking and the contents of the rucksacks afterwards.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    contents: Vec<Counts>,
+    /// The moves ordered by the rucksack they take items from.
+    moves: Vec<Move>,
+    group_size: usize,
+}
+
+impl Plan {
+    /// Plans the repacking of the rucksacks in groups of `group_size`, where the last group may be smaller.
+    ///
+    /// The groups are repacked first, which either removes all but one shared item type from one rucksack each or
+    /// puts the same item type into every rucksack. The cheapest choice also accounts for the items that have to
+    /// leave their compartment anyway, because the other compartment has items of the same type. Afterwards, the
+    /// smaller of two compartments with the same item type moves its items of that type to the larger one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_size` is 0 or if a rucksack contains a byte that is not an item type, which the parser of
+    /// [`Day3`](crate::Day3) rules out.
+    pub fn new(rucksacks: &[Vec<u8>], group_size: usize) -> Result<Self, NoPlan> {
+        assert!(group_size > 0, "a group needs at least one elf");
+
+        let mut plan = Self {
+            contents: rucksacks.iter().map(|r| counts(r)).collect(),
+            moves: Vec::new(),
+            group_size,
+        };
+
+        for start in (0..rucksacks.len()).step_by(group_size) {
+            plan.single_badge(start..(start + group_size).min(rucksacks.len()))?;
+        }
+        for rucksack in 0..rucksacks.len() {
+            plan.separate(rucksack);
+        }
+
+        plan.moves.sort_by_key(|m| m.from.rucksack);
+        Ok(plan)
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// The moves that take items out of the given rucksack.
+    pub fn moves_from(&self, rucksack: usize) -> impl Iterator<Item = &Move> {
+        self.moves
+            .iter()
+            .filter(move |m| m.from.rucksack == rucksack)
+    }
+
+    /// The number of items that are moved.
+    pub fn moved_items(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+
+    /// The item types in the two compartments of a rucksack after repacking.
+    pub fn compartments(&self, rucksack: usize) -> (ItemSet, ItemSet) {
+        let [c1, c2] = &self.contents[rucksack];
+        (set(c1), set(c2))
+    }
+
+    /// The answer of part 1 after repacking, which is 0 because no compartments share an item type.
+    pub fn shared_priorities(&self) -> u32 {
+        (0..self.contents.len())
+            .map(|r| {
+                let (c1, c2) = self.compartments(r);
+                (c1 & c2).priorities()
+            })
+            .sum()
+    }
+
+    /// The answer of part 2 after repacking, which is the sum of the priorities of the single badge of each group.
+    pub fn badge_priorities(&self) -> u32 {
+        (0..self.contents.len())
+            .step_by(self.group_size)
+            .map(|start| {
+                let group = start..(start + self.group_size).min(self.contents.len());
+                self.common(group).priorities()
+            })
+            .sum()
+    }
+
+    /// The items of type `t` in the two compartments of a rucksack.
+    fn count(&self, rucksack: usize, t: usize) -> (usize, usize) {
+        let [c1, c2] = &self.contents[rucksack];
+        (c1[t], c2[t])
+    }
+
+    fn items(&self, rucksack: usize) -> ItemSet {
+        let (c1, c2) = self.compartments(rucksack);
+        c1 | c2
+    }
+
+    fn common(&self, group: Range<usize>) -> ItemSet {
+        group
+            .map(|r| self.items(r))
+            .reduce(|a, b| a & b)
+            .unwrap_or_default()
+    }
+
+    fn single_badge(&mut self, group: Range<usize>) -> Result<(), NoPlan> {
+        let common = self.common(group.clone());
+        match common.len() {
+            1 => Ok(()),
+            0 => self.add_badge(group),
+            _ => self.remove_badges(group, common),
+        }
+    }
+
+    /// Removes all shared item types but one from one rucksack each.
+    fn remove_badges(&mut self, group: Range<usize>, common: ItemSet) -> Result<(), NoPlan> {
+        if group.len() < 2 {
+            return Err(NoPlan {
+                line: group.start + 1,
+            });
+        }
+
+        // removing the items of the smaller compartment costs nothing extra, because they have to move anyway
+        let donor = |plan: &Self, t: usize| {
+            group
+                .clone()
+                .map(|r| {
+                    let (a, b) = plan.count(r, t);
+                    (a.max(b), r)
+                })
+                .min()
+                .expect("a group is not empty")
+        };
+
+        // keep the type that is the most expensive to remove (the first one if several are equally expensive)
+        let types: Vec<usize> = common.items().map(index).collect();
+        let keep = types
+            .iter()
+            .copied()
+            .rev()
+            .max_by_key(|&t| donor(self, t).0)
+            .expect("the group shares at least two item types");
+
+        for t in types.into_iter().filter(|&t| t != keep) {
+            let (_, from) = donor(self, t);
+            let to = group.clone().find(|&r| r != from).expect("checked above");
+            let (a, b) = self.count(to, t);
+            let to = Place {
+                rucksack: to,
+                compartment: usize::from(b > a),
+            };
+
+            let (a, b) = self.count(from, t);
+            for (compartment, count) in [a, b].into_iter().enumerate() {
+                let from = Place {
+                    rucksack: from,
+                    compartment,
+                };
+                self.shift(t, count, from, to);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Puts an item of the same type into every rucksack that lacks it.
+    fn add_badge(&mut self, group: Range<usize>) -> Result<(), NoPlan> {
+        let total = |plan: &Self, r: usize, t: usize| {
+            let (a, b) = plan.count(r, t);
+            a + b
+        };
+
+        // a move costs nothing extra if it takes an item of the smaller of two compartments with this type, and the
+        // rucksacks that give items away have to keep at least one
+        let cost = |t: usize| {
+            let holders: Vec<usize> = group.clone().filter(|&r| total(self, r, t) > 0).collect();
+            let lacking = group.len() - holders.len();
+            let spare: usize = holders.iter().map(|&r| total(self, r, t) - 1).sum();
+            let free: usize = holders
+                .iter()
+                .map(|&r| {
+                    let (a, b) = self.count(r, t);
+                    a.min(b)
+                })
+                .sum();
+
+            (!holders.is_empty() && spare >= lacking).then(|| lacking - free.min(lacking))
+        };
+
+        let t = (0..52)
+            .filter_map(|t| Some((cost(t)?, t)))
+            .min()
+            .map(|(_, t)| t)
+            .ok_or(NoPlan {
+                line: group.start + 1,
+            })?;
+
+        let lacking: Vec<usize> = group.clone().filter(|&r| total(self, r, t) == 0).collect();
+        for to in lacking {
+            let shared = group.clone().find(|&r| {
+                let (a, b) = self.count(r, t);
+                a > 0 && b > 0
+            });
+            let from = match shared {
+                Some(r) => {
+                    let (a, b) = self.count(r, t);
+                    Place {
+                        rucksack: r,
+                        compartment: usize::from(a >= b),
+                    }
+                }
+                None => {
+                    let r = group
+                        .clone()
+                        .find(|&r| total(self, r, t) >= 2)
+                        .expect("there are enough spare items");
+                    Place {
+                        rucksack: r,
+                        compartment: usize::from(self.count(r, t).0 == 0),
+                    }
+                }
+            };
+
+            let to = Place {
+                rucksack: to,
+                compartment: 0,
+            };
+            self.shift(t, 1, from, to);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the items of the smaller compartment of each type that is in both compartments to the other one.
+    fn separate(&mut self, rucksack: usize) {
+        let place = |compartment| Place {
+            rucksack,
+            compartment,
+        };
+
+        for t in 0..52 {
+            match self.count(rucksack, t) {
+                (a, b) if a == 0 || b == 0 => {}
+                (a, b) if a >= b => self.shift(t, b, place(1), place(0)),
+                (a, _) => self.shift(t, a, place(0), place(1)),
+            }
+        }
+    }
+
+    /// Moves `count` items of type `t`, merging the move with the previous one if it is between the same places.
+    fn shift(&mut self, t: usize, count: usize, from: Place, to: Place) {
+        if count == 0 {
+            return;
+        }
+
+        self.contents[from.rucksack][from.compartment][t] -= count;
+        self.contents[to.rucksack][to.compartment][t] += count;
+
+        let item = item(t as u32 + 1).expect("there are 52 item types");
+        match self.moves.last_mut() {
+            Some(last) if (last.item, last.from, last.to) == (item, from, to) => {
+                last.count += count
+            }
+            _ => self.moves.push(Move {
+                item,
+                count,
+                from,
+                to,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Plan {
+    /// One move per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for m in &self.moves {
+            writeln!(f, "{m}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The index of an item type in [`Counts`].
+fn index(item: u8) -> usize {
+    priority(item).expect("an item type") as usize - 1
+}
+
+fn counts(rucksack: &[u8]) -> Counts {
+    let mut counts = [[0; 52]; 2];
+    let (c1, c2) = rucksack.split_at(rucksack.len() / 2);
+    for (compartment, items) in [c1, c2].into_iter().enumerate() {
+        for &item in items {
+            counts[compartment][index(item)] += 1;
+        }
+    }
+
+    counts
+}
+
+fn set(counts: &[usize; 52]) -> ItemSet {
+    (0..52)
+        .filter(|&t| counts[t] > 0)
+        .filter_map(|t| item(t as u32 + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::generate::generate;
+
+    fn rucksacks(input: &str) -> Vec<Vec<u8>> {
+        input.lines().map(|line| line.as_bytes().to_vec()).collect()
+    }
+
+    fn assert_repacked(plan: &Plan, rucksacks: usize, group_size: usize) {
+        assert_eq!(plan.shared_priorities(), 0);
+        for start in (0..rucksacks).step_by(group_size) {
+            let group = start..(start + group_size).min(rucksacks);
+            assert_eq!(plan.common(group).len(), 1);
+        }
+    }
+
+    #[test]
+    fn sample() {
+        let rucksacks = rucksacks(
+            "vJrwpWtwJgWrhcsFMMfFFhFp\n\
+             jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n\
+             PmmdzqPrVvPwwTWBwg\n\
+             wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n\
+             ttgJtRGJQctTZtZT\n\
+             CrZsJsPPZsGzwwsLwLmpwMDw",
+        );
+
+        // every group already has one badge, and each rucksack moves the items of its shared type from the smaller
+        // compartment
+        let plan = Plan::new(&rucksacks, 3).unwrap();
+        assert_repacked(&plan, rucksacks.len(), 3);
+        assert_eq!(plan.badge_priorities(), 70);
+        assert_eq!(plan.moves_from(0).count(), 1);
+        assert!(plan
+            .moves()
+            .iter()
+            .all(|m| m.from.rucksack == m.to.rucksack));
+    }
+
+    #[test]
+    fn adds_badge() {
+        // only g has a spare item
+        let plan = Plan::new(&rucksacks("abcd\nefgg"), 2).unwrap();
+        let expected = Move {
+            item: b'g',
+            count: 1,
+            from: Place {
+                rucksack: 1,
+                compartment: 1,
+            },
+            to: Place {
+                rucksack: 0,
+                compartment: 0,
+            },
+        };
+        assert_eq!(plan.moves(), [expected]);
+        assert_eq!(plan.badge_priorities(), 7);
+    }
+
+    #[test]
+    fn moves_that_are_needed_anyway_are_free() {
+        // each rucksack has to move two items anyway, and the a that moves can go into the other rucksack instead
+        let plan = Plan::new(&rucksacks("cdcd\nabab"), 2).unwrap();
+        assert_repacked(&plan, 2, 2);
+        assert_eq!(plan.moved_items(), 4);
+        assert_eq!(plan.badge_priorities(), 1);
+    }
+
+    #[test]
+    fn takes_badges_from_the_smaller_compartment() {
+        // the a moving from the first compartment of the first rucksack separates its compartments as well
+        let plan = Plan::new(&rucksacks("acaa\nbbbb"), 2).unwrap();
+        assert_repacked(&plan, 2, 2);
+        assert_eq!(plan.moved_items(), 3);
+        assert_eq!(plan.moves_from(0).next().unwrap().from.compartment, 0);
+    }
+
+    #[test]
+    fn impossible_groups() {
+        assert_eq!(Plan::new(&rucksacks("ab\ncd"), 2).unwrap_err().line, 1);
+        assert_eq!(Plan::new(&rucksacks("aa\nab"), 1).unwrap_err().line, 2);
+    }
+
+    /// Whether a group cannot be repacked: a single rucksack can only share one item type with itself if it has
+    /// exactly one, and a badge can only be added if some item type has an item for every rucksack.
+    fn is_impossible(group: &[Vec<u8>]) -> bool {
+        let sets: Vec<ItemSet> = group.iter().map(|r| ItemSet::from_items(r)).collect();
+        if let [set] = sets[..] {
+            return set.len() != 1;
+        }
+
+        let common = sets.iter().fold(ItemSet::ALL, |a, &b| a & b);
+        let items = group.concat();
+        common.is_empty()
+            && !ItemSet::ALL
+                .items()
+                .any(|t| items.iter().filter(|&&i| i == t).count() >= group.len())
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for group_size in 1..=4 {
+            for seed in 0..8 {
+                let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+                let rucksacks = rucksacks(&input);
+                match Plan::new(&rucksacks, group_size) {
+                    Ok(plan) => assert_repacked(&plan, rucksacks.len(), group_size),
+                    Err(NoPlan { line }) => {
+                        // the generator gives every group of three a badge
+                        assert_ne!(group_size, 3, "seed {seed}");
+                        let group =
+                            &rucksacks[line - 1..(line - 1 + group_size).min(rucksacks.len())];
+                        assert!(is_impossible(group), "seed {seed}, line {line}");
+                        for start in (0..line - 1).step_by(group_size) {
+                            let group = &rucksacks[start..start + group_size];
+                            assert!(!is_impossible(group), "seed {seed}, line {}", start + 1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
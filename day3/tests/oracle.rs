@@ -0,0 +1,91 @@
+//! Compares the number of moved items of a repacking plan against a breadth-first search over single item moves.
+
+use std::collections::{HashSet, VecDeque};
+
+use day3::repack::Plan;
+use proptest::prelude::*;
+
+/// The item types of the generated rucksacks.
+const TYPES: &[u8] = b"abc";
+
+/// The items of each type in the two compartments of each rucksack of a group.
+type State = Vec<[[u8; 3]; 2]>;
+
+/// A single group of rucksacks with an even number of items each, small enough to search exhaustively.
+fn group() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    let sizes = prop_oneof![
+        Just((1, 2)),
+        Just((1, 4)),
+        Just((2, 2)),
+        Just((2, 4)),
+        Just((3, 2))
+    ];
+    sizes.prop_flat_map(|(rucksacks, len)| {
+        let rucksack = prop::collection::vec(prop::sample::select(TYPES), len);
+        prop::collection::vec(rucksack, rucksacks)
+    })
+}
+
+fn state(group: &[Vec<u8>]) -> State {
+    group
+        .iter()
+        .map(|rucksack| {
+            let mut counts = [[0; 3]; 2];
+            let (c1, c2) = rucksack.split_at(rucksack.len() / 2);
+            for (compartment, items) in [c1, c2].into_iter().enumerate() {
+                for &item in items {
+                    counts[compartment][(item - b'a') as usize] += 1;
+                }
+            }
+            counts
+        })
+        .collect()
+}
+
+/// Whether no compartments share an item type and the rucksacks share exactly one.
+fn is_repacked(state: &State) -> bool {
+    let separate = state
+        .iter()
+        .all(|[c1, c2]| (0..3).all(|t| c1[t] == 0 || c2[t] == 0));
+    let common = (0..3)
+        .filter(|&t| state.iter().all(|[c1, c2]| c1[t] + c2[t] > 0))
+        .count();
+    separate && common == 1
+}
+
+/// The fewest single item moves that repack the group, or `None` if it cannot be repacked.
+fn fewest_moves(group: &[Vec<u8>]) -> Option<usize> {
+    let start = state(group);
+    let places: Vec<(usize, usize)> = (0..start.len()).flat_map(|r| [(r, 0), (r, 1)]).collect();
+
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, moves)) = queue.pop_front() {
+        if is_repacked(&state) {
+            return Some(moves);
+        }
+
+        for &(r1, c1) in &places {
+            for t in (0..3).filter(|&t| state[r1][c1][t] > 0) {
+                for &(r2, c2) in places.iter().filter(|&&p| p != (r1, c1)) {
+                    let mut next = state.clone();
+                    next[r1][c1][t] -= 1;
+                    next[r2][c2][t] += 1;
+                    if seen.insert(next.clone()) {
+                        queue.push_back((next, moves + 1));
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+proptest! {
+    #[test]
+    fn moves_as_few_items_as_possible(group in group()) {
+        let plan = Plan::new(&group, group.len());
+        prop_assert_eq!(plan.map(|p| p.moved_items()).ok(), fewest_moves(&group));
+    }
+}
@@ -0,0 +1,33 @@
+//! Random section assignments.
+
+use rand::Rng;
+
+/// Generates `size` pairs of section assignments.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut range = || {
+        let from = rng.gen_range(1..=99);
+        let to = rng.gen_range(from..=99);
+        format!("{from}-{to}")
+    };
+
+    (0..size.max(1))
+        .map(|_| format!("{},{}\n", range(), range()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day4.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Day 4: Camp Cleanup
+
+use std::fmt::Write;
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use sections::{Assignment, Camp, Relation};
+
+pub mod generate;
+pub mod sections;
+
+fn parse_assignment(line: &mut Line) -> Result<Assignment, ParseError> {
+    let first = line.integer()?;
+    line.expect("-")?;
+    let column = line.column();
+    let last: i32 = line.integer()?;
+    if last < first {
+        return Err(ParseError::new(
+            line.number(),
+            column,
+            format!("a section of at least {first}"),
+        )
+        .found(last.to_string()));
+    }
+
+    Ok(Assignment::new(first, last))
+}
+
+fn parse_line(mut line: Line) -> Result<(Assignment, Assignment), ParseError> {
+    let a1 = parse_assignment(&mut line)?;
+    line.expect(",")?;
+    let a2 = parse_assignment(&mut line)?;
+    line.end()?;
+    Ok((a1, a2))
+}
+
+/// The number of pairs whose assignments have a relation with the given property.
+fn count(pairs: &[(Assignment, Assignment)], property: fn(Relation) -> bool) -> usize {
+    pairs
+        .iter()
+        .filter(|(a1, a2)| property(a1.relation(*a2)))
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input).map(parse_line).collect()
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count(pairs, Relation::is_containment).into())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Ok(Some(count(pairs, Relation::shares_sections).into()))
+    }
+
+    /// How the assignments of all elves cover the camp, see [`Camp`].
+    fn analysis(&self, pairs: &Self::Input) -> Option<String> {
+        let camp = Camp::new(pairs.iter().flat_map(|&(a1, a2)| [a1, a2]));
+        let mut uncovered: Vec<String> = camp
+            .uncovered()
+            .into_iter()
+            .map(|sections| match (sections.start(), sections.end()) {
+                (first, last) if first == last => first.to_string(),
+                (first, last) => format!("{first}-{last}"),
+            })
+            .collect();
+        if uncovered.is_empty() {
+            uncovered.push("none".to_owned());
+        }
+
+        let mut report = String::new();
+        writeln!(report, "uncovered sections: {}", uncovered.join(", ")).unwrap();
+        writeln!(report, "max coverage: {}", camp.max_coverage()).unwrap();
+        writeln!(report, "redundant elves: {}", camp.redundant().len()).unwrap();
+
+        Some(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn part1() {
+        let input = Day4.parse(SAMPLE).unwrap();
+        assert_eq!(Day4.part1(&input).unwrap(), 2.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day4.parse(SAMPLE).unwrap();
+        assert_eq!(Day4.part2(&input).unwrap(), Some(4.into()));
+    }
+
+    #[test]
+    fn analysis() {
+        // only 7-9 covers section 9
+        let input = Day4.parse(SAMPLE).unwrap();
+        assert_eq!(
+            Day4.analysis(&input).unwrap(),
+            "uncovered sections: none\nmax coverage: 8\nredundant elves: 11\n"
+        );
+
+        let input = Day4.parse("1-2,5-5\n7-9,8-8\n").unwrap();
+        assert_eq!(
+            Day4.analysis(&input).unwrap(),
+            "uncovered sections: 3-4, 6\nmax coverage: 2\nredundant elves: 1\n"
+        );
+    }
+
+    #[test]
+    fn reversed_assignment() {
+        let error = Day4.parse("2-4,6-8\n2-3,5-4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected a section of at least 5, found 4"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Section assignments, the relations between them and queries about how they cover the camp.
+//!
+//! An assignment of the sections `l` to `u` is treated as the interval from the start of section `l` to the end of
+//! section `u`, so two assignments without a section in common but without a gap either *meet*.
+//!
+//! ```
+//! use day4::sections::{Assignment, Camp, Relation};
+//!
+//! let a = Assignment::new(2, 3);
+//! assert_eq!((a.first(), a.last()), (2, 3));
+//! assert_eq!(a.relation(Assignment::new(4, 5)), Relation::Meets);
+//! assert_eq!(a.relation(Assignment::new(2, 8)), Relation::Starts);
+//!
+//! let camp = Camp::new([a, Assignment::new(6, 8), Assignment::new(3, 7)]);
+//! assert_eq!(camp.uncovered(), []);
+//! assert_eq!(camp.max_coverage(), 2);
+//! ```
+
+use std::ops::RangeInclusive;
+
+/// The inclusive range of sections assigned to an elf, which has at least one section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Assignment {
+    first: i32,
+    last: i32,
+}
+
+impl Assignment {
+    /// # Panics
+    ///
+    /// Panics if `last` is smaller than `first`.
+    pub fn new(first: i32, last: i32) -> Self {
+        assert!(first <= last, "an assignment has at least one section");
+        Self { first, last }
+    }
+
+    pub fn first(self) -> i32 {
+        self.first
+    }
+
+    pub fn last(self) -> i32 {
+        self.last
+    }
+
+    pub fn sections(self) -> RangeInclusive<i32> {
+        self.first..=self.last
+    }
+
+    pub fn contains(self, section: i32) -> bool {
+        self.sections().contains(&section)
+    }
+
+    /// Allen's interval relation of `self` to `other`.
+    pub fn relation(self, other: Self) -> Relation {
+        use std::cmp::Ordering::*;
+
+        // the intervals end after the last section, so an assignment ending right before the other starts meets it
+        // (computed as i64 because the end of section i32::MAX is outside of i32)
+        let (start, end) = (i64::from(self.first), i64::from(self.last) + 1);
+        let (other_start, other_end) = (i64::from(other.first), i64::from(other.last) + 1);
+        if end < other_start {
+            return Relation::Before;
+        }
+        if end == other_start {
+            return Relation::Meets;
+        }
+        if other_end < start {
+            return Relation::After;
+        }
+        if other_end == start {
+            return Relation::MetBy;
+        }
+
+        match (start.cmp(&other_start), end.cmp(&other_end)) {
+            (Less, Less) => Relation::Overlaps,
+            (Less, Equal) => Relation::FinishedBy,
+            (Less, Greater) => Relation::Contains,
+            (Equal, Less) => Relation::Starts,
+            (Equal, Equal) => Relation::Equals,
+            (Equal, Greater) => Relation::StartedBy,
+            (Greater, Less) => Relation::During,
+            (Greater, Equal) => Relation::Finishes,
+            (Greater, Greater) => Relation::OverlappedBy,
+        }
+    }
+}
+
+/// The 13 relations between two intervals according to Allen's interval algebra, from the view of the first interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Before,
+    Meets,
+    Overlaps,
+    FinishedBy,
+    Contains,
+    Starts,
+    Equals,
+    StartedBy,
+    During,
+    Finishes,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 13] = [
+        Relation::Before,
+        Relation::Meets,
+        Relation::Overlaps,
+        Relation::FinishedBy,
+        Relation::Contains,
+        Relation::Starts,
+        Relation::Equals,
+        Relation::StartedBy,
+        Relation::During,
+        Relation::Finishes,
+        Relation::OverlappedBy,
+        Relation::MetBy,
+        Relation::After,
+    ];
+
+    /// The relation of the second interval to the first one.
+    pub fn inverse(self) -> Self {
+        Self::ALL[Self::ALL.len() - 1 - self as usize]
+    }
+
+    /// Whether one of the intervals contains the other one.
+    pub fn is_containment(self) -> bool {
+        matches!(
+            self,
+            Self::FinishedBy
+                | Self::Contains
+                | Self::Starts
+                | Self::Equals
+                | Self::StartedBy
+                | Self::During
+                | Self::Finishes
+        )
+    }
+
+    /// Whether the intervals have at least one section in common.
+    pub fn shares_sections(self) -> bool {
+        !matches!(self, Self::Before | Self::Meets | Self::MetBy | Self::After)
+    }
+}
+
+/// The assignments of all elves.
+#[derive(Debug, Clone, Default)]
+pub struct Camp {
+    elves: Vec<Assignment>,
+    /// The number of elves covering each section from the first to the last assigned section, as runs
+    /// `(first, last, elves)` of sections covered by the same number of elves.
+    coverage: Vec<(i32, i32, usize)>,
+}
+
+impl Camp {
+    pub fn new(elves: impl IntoIterator<Item = Assignment>) -> Self {
+        let elves: Vec<Assignment> = elves.into_iter().collect();
+
+        // +1 where an assignment starts and -1 after it ends, which may be right after section i32::MAX
+        let mut events: Vec<(i64, isize)> = elves
+            .iter()
+            .flat_map(|a| [(a.first.into(), 1), (i64::from(a.last) + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut coverage = Vec::new();
+        let mut elves_here = 0;
+        for (i, &(section, change)) in events.iter().enumerate() {
+            elves_here += change;
+            match events.get(i + 1) {
+                // a run ends before a later event, so both ends are sections
+                Some(&(next, _)) if next > section => {
+                    coverage.push((section as i32, (next - 1) as i32, elves_here as usize));
+                }
+                _ => {}
+            }
+        }
+
+        Self { elves, coverage }
+    }
+
+    pub fn elves(&self) -> &[Assignment] {
+        &self.elves
+    }
+
+    /// The number of elves covering `section`.
+    pub fn coverage(&self, section: i32) -> usize {
+        self.runs(Assignment::new(section, section))
+            .next()
+            .map_or(0, |&(_, _, elves)| elves)
+    }
+
+    /// The ranges of sections between the first and the last assigned section that no elf covers.
+    pub fn uncovered(&self) -> Vec<RangeInclusive<i32>> {
+        self.coverage
+            .iter()
+            .filter(|&&(_, _, elves)| elves == 0)
+            .map(|&(first, last, _)| first..=last)
+            .collect()
+    }
+
+    /// The largest number of elves covering the same section.
+    pub fn max_coverage(&self) -> usize {
+        self.coverage
+            .iter()
+            .map(|&(_, _, elves)| elves)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The indices of the elves whose sections are all covered by other elves as well.
+    ///
+    /// Each of them could be left out on its own, but leaving out several of them may leave sections uncovered, e.g.
+    /// when two elves have the same assignment.
+    pub fn redundant(&self) -> Vec<usize> {
+        (0..self.elves.len())
+            .filter(|&i| self.runs(self.elves[i]).all(|&(_, _, elves)| elves >= 2))
+            .collect()
+    }
+
+    /// The runs of [`Camp::coverage`] that overlap the sections of `assignment`.
+    fn runs(&self, assignment: Assignment) -> impl Iterator<Item = &(i32, i32, usize)> {
+        let start = self
+            .coverage
+            .partition_point(|&(_, last, _)| last < assignment.first);
+        self.coverage[start..]
+            .iter()
+            .take_while(move |&&(first, _, _)| first <= assignment.last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relations() {
+        let a = Assignment::new(4, 6);
+        let cases = [
+            ((1, 2), Relation::After),
+            ((1, 3), Relation::MetBy),
+            ((1, 4), Relation::OverlappedBy),
+            ((1, 8), Relation::During),
+            ((2, 6), Relation::Finishes),
+            ((4, 5), Relation::StartedBy),
+            ((4, 6), Relation::Equals),
+            ((4, 8), Relation::Starts),
+            ((5, 5), Relation::Contains),
+            ((5, 6), Relation::FinishedBy),
+            ((6, 8), Relation::Overlaps),
+            ((7, 8), Relation::Meets),
+            ((8, 9), Relation::Before),
+        ];
+
+        for ((first, last), relation) in cases {
+            let b = Assignment::new(first, last);
+            assert_eq!(a.relation(b), relation, "{a:?} and {b:?}");
+            assert_eq!(b.relation(a), relation.inverse(), "{b:?} and {a:?}");
+        }
+    }
+
+    #[test]
+    fn last_section() {
+        let a = Assignment::new(i32::MAX - 1, i32::MAX);
+        assert_eq!(
+            a.relation(Assignment::new(i32::MAX, i32::MAX)),
+            Relation::FinishedBy
+        );
+        assert_eq!(
+            Assignment::new(0, i32::MAX - 1).relation(Assignment::new(i32::MAX, i32::MAX)),
+            Relation::Meets
+        );
+    }
+
+    #[test]
+    fn every_relation_has_an_inverse() {
+        for relation in Relation::ALL {
+            assert_eq!(relation.inverse().inverse(), relation);
+            assert_eq!(
+                relation.inverse().is_containment(),
+                relation.is_containment()
+            );
+            assert_eq!(
+                relation.inverse().shares_sections(),
+                relation.shares_sections()
+            );
+        }
+    }
+
+    #[test]
+    fn coverage() {
+        let camp = Camp::new(
+            [(2, 4), (3, 5), (8, 8), (4, 4), (10, 12), (11, 12)]
+                .map(|(f, l)| Assignment::new(f, l)),
+        );
+        assert_eq!(camp.uncovered(), [6..=7, 9..=9]);
+        assert_eq!(camp.max_coverage(), 3);
+        assert_eq!(camp.coverage(4), 3);
+        assert_eq!(camp.coverage(9), 0);
+        assert_eq!(camp.coverage(20), 0);
+        assert_eq!(camp.redundant(), [3, 5]);
+
+        let camp = Camp::new([
+            Assignment::new(i32::MAX - 1, i32::MAX),
+            Assignment::new(i32::MAX, i32::MAX),
+        ]);
+        assert_eq!(camp.max_coverage(), 2);
+        assert_eq!(camp.coverage(i32::MAX), 2);
+        assert_eq!(camp.redundant(), [1]);
+
+        let empty = Camp::default();
+        assert_eq!(empty.uncovered(), []);
+        assert_eq!(empty.max_coverage(), 0);
+    }
+}
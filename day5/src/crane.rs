@@ -0,0 +1,246 @@
+//! Crane models and the execution of moves with them.
+//!
+//! A crane moves the crates of a move in lifts of as many crates as it can carry, where each lift keeps the order of
+//! its crates. The CrateMover 9000 lifts one crate at a time and so reverses the order of the moved crates, while the
+//! CrateMover 9001 lifts all of them at once.
+//!
+//! ```
+//! use common::Solution;
+//! use day5::{crane::{Crane, LimitedCrane}, Day5};
+//!
+//! let input = "[A]\n[B]\n[C]\n 1   2\n\nmove 3 from 1 to 2\n";
+//! let procedure = Day5::default().parse(input).unwrap();
+//! let stacks = LimitedCrane::new(2).run(procedure.stacks(), procedure.moves()).unwrap();
+//! assert_eq!(stacks.stack(1), b"BAC");
+//! ```
+
+use thiserror::Error;
+
+use crate::{stacks::Stacks, Move};
+
+/// A move that cannot be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum MoveError {
+    #[error("line {line}: there is no stack {stack}")]
+    NoStack { line: usize, stack: usize },
+    #[error("line {line}: cannot move {count} crates from stack {stack}, which has {crates}")]
+    NotEnoughCrates {
+        line: usize,
+        stack: usize,
+        count: usize,
+        crates: usize,
+    },
+}
+
+impl MoveError {
+    /// The line of the move.
+    pub fn line(&self) -> usize {
+        match *self {
+            Self::NoStack { line, .. } | Self::NotEnoughCrates { line, .. } => line,
+        }
+    }
+}
+
+/// A model of a crane, which only differs in how many crates it lifts at once.
+///
+/// Cranes with other restrictions can override [`Crane::execute`], and [`Day5`](crate::Day5) reports the errors of
+/// their moves instead of answers.
+///
+/// ```
+/// use common::Solution;
+/// use day5::{crane::{Crane, CrateMover9000, MoveError}, stacks::Stacks, Day5, Move};
+///
+/// /// A crane that cannot reach the first stack to take crates from it.
+/// struct Blocked;
+///
+/// impl Crane for Blocked {
+///     fn capacity(&self) -> Option<usize> {
+///         Some(1)
+///     }
+///
+///     fn execute(&self, stacks: &mut Stacks, m: &Move) -> Result<(), MoveError> {
+///         if m.from() == 1 {
+///             return Err(MoveError::NoStack { line: m.line(), stack: m.from() });
+///         }
+///         CrateMover9000.execute(stacks, m)
+///     }
+/// }
+///
+/// let day = Day5 { part1: Box::new(Blocked), part2: Box::new(CrateMover9000) };
+/// let procedure = day.parse("[A]\n[B] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 2 from 1 to 2\n").unwrap();
+/// let error = day.part1(&procedure).unwrap_err();
+/// assert_eq!(error.to_string(), "line 6: there is no stack 1");
+/// assert_eq!(day.part2(&procedure).unwrap(), Some("BA".into()));
+/// ```
+pub trait Crane {
+    /// The largest number of crates the crane lifts at once, or `None` if it lifts any number.
+    fn capacity(&self) -> Option<usize>;
+
+    /// Executes a move, lifting as many crates as possible at once until all crates are moved.
+    fn execute(&self, stacks: &mut Stacks, m: &Move) -> Result<(), MoveError> {
+        for stack in [m.from, m.to] {
+            if !(1..=stacks.len()).contains(&stack) {
+                return Err(MoveError::NoStack {
+                    line: m.line,
+                    stack,
+                });
+            }
+        }
+
+        let crates = stacks.stack(m.from - 1).len();
+        if m.count > crates {
+            return Err(MoveError::NotEnoughCrates {
+                line: m.line,
+                stack: m.from,
+                count: m.count,
+                crates,
+            });
+        }
+
+        let lift = self.capacity().unwrap_or(m.count);
+        let mut left = m.count;
+        while left > 0 {
+            let from = stacks.stack_mut(m.from - 1);
+            let lifted = from.split_off(from.len() - left.min(lift));
+            left -= lifted.len();
+            stacks.stack_mut(m.to - 1).extend(lifted);
+        }
+
+        Ok(())
+    }
+
+    /// Executes the moves in order and returns the resulting stacks.
+    fn run(&self, stacks: &Stacks, moves: &[Move]) -> Result<Stacks, MoveError> {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            self.execute(&mut stacks, m)?;
+            tracing::trace!("after line {}:\n{stacks}", m.line);
+        }
+
+        Ok(stacks)
+    }
+
+    /// The stacks before the first move and after each move.
+    fn trace(&self, stacks: &Stacks, moves: &[Move]) -> Result<Vec<Stacks>, MoveError> {
+        let mut states = vec![stacks.clone()];
+        for m in moves {
+            let mut next = states[states.len() - 1].clone();
+            self.execute(&mut next, m)?;
+            states.push(next);
+        }
+
+        Ok(states)
+    }
+}
+
+/// Lifts one crate at a time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn capacity(&self) -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Lifts all crates of a move at once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Lifts at most a fixed number of crates at once.
+#[derive(Debug, Clone, Copy)]
+pub struct LimitedCrane {
+    capacity: usize,
+}
+
+impl LimitedCrane {
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a crane lifts at least one crate");
+        Self { capacity }
+    }
+}
+
+impl Crane for LimitedCrane {
+    fn capacity(&self) -> Option<usize> {
+        Some(self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{generate::generate, Day5, Procedure};
+
+    fn sample() -> Procedure {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n\
+                     move 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n";
+        Day5::default().parse(input).unwrap()
+    }
+
+    #[test]
+    fn limited_crane() {
+        let Procedure { stacks, moves } = sample();
+        // the second move lifts N and D first and then Z
+        let stacks = LimitedCrane::new(2).run(&stacks, &moves).unwrap();
+        assert_eq!(stacks.top_crates(), "MCZ");
+    }
+
+    #[test]
+    fn limits_of_one_and_more_than_any_move_match_the_models() {
+        for seed in 0..8 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+            let Procedure { stacks, moves } = Day5::default().parse(&input).unwrap();
+            let run = |crane: &dyn Crane| crane.run(&stacks, &moves).unwrap();
+            assert_eq!(run(&LimitedCrane::new(1)), run(&CrateMover9000));
+            assert_eq!(run(&LimitedCrane::new(100)), run(&CrateMover9001));
+        }
+    }
+
+    #[test]
+    fn trace() {
+        let Procedure { stacks, moves } = sample();
+        let states = CrateMover9000.trace(&stacks, &moves).unwrap();
+        assert_eq!(states.len(), moves.len() + 1);
+        assert_eq!(states[0], stacks);
+        assert_eq!(states[1].stack(0), b"ZND");
+        assert_eq!(states[4].top_crates(), "CMZ");
+    }
+
+    #[test]
+    fn too_many_crates() {
+        let Procedure { stacks, moves } = sample();
+        // after the first two moves, the first stack is empty
+        let error = CrateMover9001.run(&stacks, &[moves[0], moves[1], moves[1]]);
+        let expected = MoveError::NotEnoughCrates {
+            line: 7,
+            stack: 1,
+            count: 3,
+            crates: 0,
+        };
+        assert_eq!(error, Err(expected));
+        assert_eq!(
+            expected.to_string(),
+            "line 7: cannot move 3 crates from stack 1, which has 0"
+        );
+    }
+
+    #[test]
+    fn missing_stack() {
+        let Procedure { moves, .. } = sample();
+        let stacks = Stacks::new(vec![b"AB".to_vec()]);
+        let error = CrateMover9000.execute(&mut stacks.clone(), &moves[0]);
+        assert_eq!(error, Err(MoveError::NoStack { line: 6, stack: 2 }));
+    }
+}
@@ -0,0 +1,57 @@
+//! Random rearrangement procedures.
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::stacks::Stacks;
+
+/// Generates a drawing of 2 to 12 stacks of different heights followed by `size` moves that never take more crates
+/// from a stack than it holds.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<u8>> = (0..rng.gen_range(2..=12))
+        .map(|_| {
+            (0..rng.gen_range(0..=8))
+                .map(|_| rng.gen_range(b'A'..=b'Z'))
+                .collect()
+        })
+        .collect();
+    let mut output = Stacks::new(stacks.clone()).to_string();
+    output.push('\n');
+
+    for _ in 0..size.max(1) {
+        let Some(from) = (0..stacks.len())
+            .filter(|&i| !stacks[i].is_empty())
+            .choose(rng)
+        else {
+            break;
+        };
+        let to = (0..stacks.len())
+            .filter(|&i| i != from)
+            .choose(rng)
+            .unwrap();
+        let count = rng.gen_range(1..=stacks[from].len().min(10));
+
+        let at = stacks[from].len() - count;
+        let moved = stacks[from].split_off(at);
+        stacks[to].extend(moved);
+        output.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day5;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day5::default().solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
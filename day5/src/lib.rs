@@ -0,0 +1,272 @@
+//! Day 5: Supply Stacks
+
+use common::{
+    parse::{self, Line, ParseError},
+    Answer, Solution, SolveError,
+};
+use crane::{Crane, CrateMover9000, CrateMover9001, MoveError};
+use stacks::Stacks;
+
+pub mod crane;
+pub mod generate;
+pub mod stacks;
+
+/// A move of the crane: `count` crates from stack `from` to stack `to`, with stacks numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+    /// The line of the move in the input.
+    line: usize,
+    /// The column of `count`, for error messages.
+    column: usize,
+}
+
+impl Move {
+    /// Parses a move like `move 1 from 2 to 3`, checking that both stacks are between 1 and `stack_count`.
+    pub fn parse(line: &mut Line, stack_count: usize) -> Result<Self, ParseError> {
+        let stack = |line: &mut Line| {
+            let column = line.column();
+            let stack = line.integer()?;
+            if (1..=stack_count).contains(&stack) {
+                Ok(stack)
+            } else {
+                Err(ParseError::new(
+                    line.number(),
+                    column,
+                    format!("a stack between 1 and {stack_count}"),
+                )
+                .found(stack.to_string()))
+            }
+        };
+
+        line.expect("move ")?;
+        let column = line.column();
+        let count = line.integer()?;
+        line.expect(" from ")?;
+        let from = stack(line)?;
+        line.expect(" to ")?;
+        let to = stack(line)?;
+        line.end()?;
+
+        Ok(Self {
+            count,
+            from,
+            to,
+            line: line.number(),
+            column,
+        })
+    }
+
+    /// The number of crates to move.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The stack to take the crates from.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// The stack to put the crates onto.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// The line of the move in the input.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+/// The initial stacks and the moves of the rearrangement procedure, which can only be parsed, so that every move has
+/// enough crates to move.
+#[derive(Debug, Clone)]
+pub struct Procedure {
+    stacks: Stacks,
+    moves: Vec<Move>,
+}
+
+impl Procedure {
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+}
+
+pub struct Day5 {
+    /// The crane that executes the moves in part 1.
+    pub part1: Box<dyn Crane + Send + Sync>,
+    /// The crane that executes the moves in part 2.
+    pub part2: Box<dyn Crane + Send + Sync>,
+}
+
+impl Default for Day5 {
+    /// The CrateMover 9000 in part 1 and the CrateMover 9001 in part 2.
+    fn default() -> Self {
+        Self {
+            part1: Box::new(CrateMover9000),
+            part2: Box::new(CrateMover9001),
+        }
+    }
+}
+
+impl Day5 {
+    /// The crates on top of the stacks after the procedure, or the [`MoveError`] of a crane that overrides
+    /// [`Crane::execute`] and fails on a move.
+    fn top_crates(crane: &dyn Crane, procedure: &Procedure) -> Result<Answer, SolveError> {
+        let stacks = crane
+            .run(&procedure.stacks, &procedure.moves)
+            .map_err(SolveError::new)?;
+        Ok(stacks.top_crates().into())
+    }
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        // the drawing of the stacks is separated from the moves by an empty line
+        let mut lines = parse::lines(input);
+        let drawing: Vec<_> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+        let stacks = Stacks::parse(&drawing)?;
+        let moves: Vec<Move> = lines
+            .map(|mut line| Move::parse(&mut line, stacks.len()))
+            .collect::<Result<_, _>>()?;
+
+        // every crane moves the same number of crates, so the moves are valid for all of them if they are valid for one
+        let mut check = stacks.clone();
+        if let Err(error) = moves
+            .iter()
+            .try_for_each(|m| CrateMover9001.execute(&mut check, m))
+        {
+            let m = moves
+                .iter()
+                .find(|m| m.line == error.line())
+                .expect("the error is about one of the moves");
+            let parse_error = match error {
+                MoveError::NotEnoughCrates { crates, .. } => {
+                    ParseError::new(m.line, m.column, format!("at most {crates} crates"))
+                        .found(m.count.to_string())
+                }
+                MoveError::NoStack { .. } => {
+                    unreachable!("the stacks are checked by `Move::parse`")
+                }
+            };
+            return Err(parse_error);
+        }
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(&self, procedure: &Self::Input) -> Result<Answer, SolveError> {
+        Self::top_crates(self.part1.as_ref(), procedure)
+    }
+
+    fn part2(&self, procedure: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        Self::top_crates(self.part2.as_ref(), procedure).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+
+    use super::*;
+
+    const SAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn part1() {
+        let input = Day5::default().parse(SAMPLE).unwrap();
+        assert_eq!(Day5::default().part1(&input).unwrap(), "CMZ".into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day5::default().parse(SAMPLE).unwrap();
+        assert_eq!(Day5::default().part2(&input).unwrap(), Some("MCD".into()));
+    }
+
+    #[test]
+    fn drawing() {
+        let input = Day5::default().parse(SAMPLE).unwrap();
+        assert_eq!(
+            input.stacks,
+            Stacks::new(vec![b"ZN".to_vec(), b"MCD".to_vec(), b"P".to_vec()])
+        );
+        assert!(SAMPLE.starts_with(&input.stacks.to_string()));
+    }
+
+    /// A crane that only reaches the first two stacks.
+    struct ShortCrane;
+
+    impl Crane for ShortCrane {
+        fn capacity(&self) -> Option<usize> {
+            None
+        }
+
+        fn execute(&self, stacks: &mut Stacks, m: &Move) -> Result<(), MoveError> {
+            match [m.from(), m.to()].into_iter().find(|&stack| stack > 2) {
+                Some(stack) => Err(MoveError::NoStack {
+                    line: m.line(),
+                    stack,
+                }),
+                None => CrateMover9001.execute(stacks, m),
+            }
+        }
+    }
+
+    #[test]
+    fn failing_crane() {
+        let day = Day5 {
+            part1: Box::new(CrateMover9000),
+            part2: Box::new(ShortCrane),
+        };
+        let input = day.parse(SAMPLE).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), "CMZ".into());
+        let error = day.part2(&input).unwrap_err();
+        assert_eq!(
+            error.error.downcast_ref::<MoveError>(),
+            Some(&MoveError::NoStack { line: 7, stack: 3 })
+        );
+
+        // the runner gets the error instead of an answer
+        let error = DynSolution::solve(&day, SAMPLE, &[Part::One, Part::Two]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 5, part 2, line 7: there is no stack 3"
+        );
+    }
+
+    #[test]
+    fn moves_need_a_drawing() {
+        let error = Day5::default().parse("move 1 from 2 to 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn moves_need_enough_crates() {
+        let input = SAMPLE.replace("move 2 from 2 to 1", "move 3 from 2 to 1");
+        let error = Day5::default().parse(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 8, column 6: expected at most 2 crates, found 3"
+        );
+    }
+}
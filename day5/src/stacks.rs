@@ -0,0 +1,210 @@
+//! Stacks of crates and the drawing of them at the start of the input.
+//!
+//! A drawing has a row for each level of crates, from the top down, followed by a line numbering the stacks from 1.
+//! Each stack takes a column of three characters, either a crate like `[A]` or spaces, with a space between the
+//! columns. Rows may end early, so the trailing spaces of a row are optional.
+//!
+//! ```
+//! use day5::stacks::Stacks;
+//!
+//! let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+//! let stacks: Stacks = drawing.parse().unwrap();
+//! assert_eq!(stacks.stack(1), b"MCD");
+//! assert_eq!(stacks.top_crates(), "NDP");
+//! assert_eq!(stacks.to_string(), drawing);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use common::parse::{self, Line, ParseError};
+
+/// Stacks of crates, numbered from 1 in the drawing but indexed from 0 here.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Stacks {
+    /// The crates of each stack, from bottom to top.
+    stacks: Vec<Vec<u8>>,
+}
+
+impl Stacks {
+    pub fn new(stacks: Vec<Vec<u8>>) -> Self {
+        Self { stacks }
+    }
+
+    /// Parses the lines of a drawing, where the last line numbers the stacks.
+    pub fn parse(lines: &[Line]) -> Result<Self, ParseError> {
+        let Some((numbers, rows)) = lines.split_last() else {
+            return Err(ParseError::end_of_input(1, "a drawing of the stacks"));
+        };
+
+        let mut stacks = vec![Vec::new(); parse_numbers(numbers.clone())?];
+        for (level, line) in rows.iter().rev().enumerate() {
+            for (i, cell) in line.rest().as_bytes().chunks(4).enumerate() {
+                let column = line.column() + 4 * i;
+                let error = |offset: usize, expected: &str| match cell.get(offset) {
+                    Some(&c) => {
+                        ParseError::unexpected(line.number(), column + offset, c as char, expected)
+                    }
+                    None => ParseError::new(line.number(), column + offset, expected),
+                };
+
+                let Some(c) =
+                    parse_cell(cell).map_err(|(offset, expected)| error(offset, expected))?
+                else {
+                    continue;
+                };
+                if i >= stacks.len() {
+                    return Err(error(0, "end of line"));
+                }
+                if stacks[i].len() < level {
+                    return Err(error(0, "a crate on top of another crate"));
+                }
+
+                stacks[i].push(c);
+            }
+        }
+
+        Ok(Self { stacks })
+    }
+
+    /// The number of stacks.
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// The crates of the stack with the given index, from bottom to top.
+    pub fn stack(&self, index: usize) -> &[u8] {
+        &self.stacks[index]
+    }
+
+    pub fn stack_mut(&mut self, index: usize) -> &mut Vec<u8> {
+        &mut self.stacks[index]
+    }
+
+    /// The crates on top of each stack, skipping empty stacks.
+    pub fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|s| s.last().map(|&c| c as char))
+            .collect()
+    }
+}
+
+/// Parses the numbers of the stacks, which must count up from 1, and returns the number of stacks.
+fn parse_numbers(mut line: Line) -> Result<usize, ParseError> {
+    let mut count = 0;
+    loop {
+        while line.eat(" ") {}
+        if line.is_empty() && count > 0 {
+            return Ok(count);
+        }
+
+        let column = line.column();
+        let number: usize = line.integer()?;
+        if number != count + 1 {
+            let expected = format!("stack {}", count + 1);
+            return Err(ParseError::new(line.number(), column, expected).found(number.to_string()));
+        }
+
+        count += 1;
+    }
+}
+
+/// Parses a column of a row, which is either a crate or empty, returning the offset of the first unexpected character
+/// and what was expected there otherwise.
+fn parse_cell(cell: &[u8]) -> Result<Option<u8>, (usize, &'static str)> {
+    let rest_is_blank =
+        |from: usize| match cell[from.min(cell.len())..].iter().position(|&b| b != b' ') {
+            Some(i) => Err((from + i, "a space")),
+            None => Ok(()),
+        };
+
+    match cell {
+        [b' ', ..] => rest_is_blank(0).map(|()| None),
+        [b'[', c, b']', ..] if c.is_ascii_graphic() => rest_is_blank(3).map(|()| Some(*c)),
+        [b'[', c, ..] if c.is_ascii_graphic() => Err((2, "\"]\"")),
+        [b'[', ..] => Err((1, "a crate")),
+        _ => Err((0, "a crate or a space")),
+    }
+}
+
+impl FromStr for Stacks {
+    type Err = ParseError;
+
+    /// Parses a drawing without anything after it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(&parse::lines(s).collect::<Vec<_>>())
+    }
+}
+
+impl fmt::Display for Stacks {
+    /// The drawing of the stacks, with every row padded to the full width.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(&c) => format!("[{}]", c as char),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+
+        let numbers: Vec<String> = (1..=self.stacks.len()).map(|n| format!("{n:^3}")).collect();
+        writeln!(f, "{}", numbers.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ragged_rows_and_many_stacks() {
+        // the trailing spaces are missing, as after normalizing the input
+        let drawing = concat!(
+            "                                            [K]\n",
+            "[A]                                         [L]\n",
+            "[B]     [C]                                 [M]\n",
+            " 1   2   3   4   5   6   7   8   9  10  11  12\n",
+        );
+        let stacks: Stacks = drawing.parse().unwrap();
+        assert_eq!(stacks.len(), 12);
+        assert_eq!(stacks.stack(0), b"BA");
+        assert!(stacks.stack(1).is_empty());
+        assert_eq!(stacks.stack(11), b"MLK");
+        assert_eq!(stacks.top_crates(), "ACK");
+
+        let rendered = stacks.to_string();
+        assert_eq!(
+            rendered.lines().last(),
+            Some(" 1   2   3   4   5   6   7   8   9  10  11  12 ")
+        );
+        assert_eq!(rendered.parse::<Stacks>().unwrap(), stacks);
+    }
+
+    #[test]
+    fn malformed_drawings() {
+        let error = |drawing: &str| {
+            let e = drawing.parse::<Stacks>().unwrap_err();
+            (e.line, e.column, e.expected)
+        };
+
+        assert_eq!(error("[A] [B\n 1   2"), (1, 7, "\"]\"".to_owned()));
+        assert_eq!(error("[A]  [B]\n 1   2"), (1, 6, "a space".to_owned()));
+        assert_eq!(error("[A] [B]\n 1"), (1, 5, "end of line".to_owned()));
+        assert_eq!(
+            error("    [A]\n[B]\n 1   2"),
+            (1, 5, "a crate on top of another crate".to_owned())
+        );
+        assert_eq!(error("[A]\n 2"), (2, 2, "stack 1".to_owned()));
+        assert_eq!(error("[A]\n"), (1, 1, "a number".to_owned()));
+        assert_eq!(error(""), (1, 1, "a drawing of the stacks".to_owned()));
+    }
+}
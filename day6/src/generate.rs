@@ -0,0 +1,37 @@
+//! Random datastreams.
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Generates a datastream of `size` characters (at least 14), which contains a run of 14 different characters at a
+/// random position.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let len = size.max(14);
+    let marker_start = rng.gen_range(0..=len - 14);
+
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    letters.shuffle(rng);
+    // all other characters come from only 13 letters, so they cannot form a start-of-message marker
+    let (marker, others) = letters.split_at(14);
+
+    let mut stream: Vec<u8> = (0..len).map(|_| *others.choose(rng).unwrap()).collect();
+    stream[marker_start..marker_start + 14].copy_from_slice(marker);
+
+    String::from_utf8(stream).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day6;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day6.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Day 6: Tuning Trouble
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+/// Whether all characters of `marker` are different.
+pub fn is_valid_marker(marker: &[u8]) -> bool {
+    for i in 1..marker.len() {
+        if marker[i..].contains(&marker[i - 1]) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Returns the number of characters up to the end of the first marker of `len` different characters.
+pub fn find_marker(datastream: &[u8], len: usize) -> Option<usize> {
+    datastream
+        .windows(len)
+        .position(is_valid_marker)
+        .map(|pos| pos + len)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(1, "a datastream"))?;
+
+        let datastream = line.rest().as_bytes();
+        if let Some(i) = datastream.iter().position(|b| !b.is_ascii_lowercase()) {
+            return Err(ParseError::unexpected(
+                line.number(),
+                i + 1,
+                datastream[i] as char,
+                "a lowercase letter",
+            ));
+        }
+
+        if let Some(line) = lines.next() {
+            return Err(line.error("end of input"));
+        }
+
+        Ok(datastream.to_vec())
+    }
+
+    fn part1(&self, datastream: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_marker(datastream, 4)
+            .expect("no start-of-packet marker")
+            .into())
+    }
+
+    fn part2(&self, datastream: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let pos = find_marker(datastream, 14).expect("no start-of-message marker");
+        Ok(Some(pos.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb
+";
+
+    #[test]
+    fn part1() {
+        let input = Day6.parse(SAMPLE).unwrap();
+        assert_eq!(Day6.part1(&input).unwrap(), 7.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day6.parse(SAMPLE).unwrap();
+        assert_eq!(Day6.part2(&input).unwrap(), Some(19.into()));
+    }
+}
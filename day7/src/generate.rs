@@ -0,0 +1,129 @@
+//! Random terminal sessions.
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+/// A directory of the generated file system.
+#[derive(Default)]
+struct Dir {
+    files: Vec<(String, u32)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+/// Generates a session that lists a file system of `size` directories, starting with `$ cd /`.
+///
+/// Unless there are many directories, two large files fill the disk to between 40000000 and 70000000. Each of them
+/// is large enough to make room for the update on its own, so the directories containing them are the candidates for
+/// deletion.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut root = Dir::default();
+    for _ in 0..size {
+        let dir = random_dir(rng, &mut root);
+        let name = unique_name(rng, dir, false);
+        dir.dirs.push((name, Dir::default()));
+    }
+
+    let total = add_files(rng, &mut root);
+    if total < 40_000_000 {
+        // the update needs 30000000 of the 70000000 on the disk, and both files must be larger than the space that
+        // has to be freed
+        let used = rng.gen_range(40_000_000..=(80_000_000 - total).min(70_000_000));
+        let needed = used - 40_000_000;
+        let first = rng.gen_range(needed..=used - total - needed);
+        for size in [first, used - total - first] {
+            let dir = random_dir(rng, &mut root);
+            let name = unique_name(rng, dir, true);
+            dir.files.push((name, size));
+        }
+    }
+
+    let mut output = "$ cd /\n".to_owned();
+    explore(rng, &root, &mut output);
+    output
+}
+
+fn random_dir<'a>(rng: &mut impl Rng, mut dir: &'a mut Dir) -> &'a mut Dir {
+    while !dir.dirs.is_empty() && rng.gen_bool(0.7) {
+        dir = &mut dir.dirs.choose_mut(rng).unwrap().1;
+    }
+
+    dir
+}
+
+/// Adds random files to `dir` and its subdirectories, returning the total size of the files.
+fn add_files(rng: &mut impl Rng, dir: &mut Dir) -> u32 {
+    for _ in 0..rng.gen_range(0..=4) {
+        let name = unique_name(rng, dir, true);
+        dir.files.push((name, rng.gen_range(1..=300_000)));
+    }
+
+    let files: u32 = dir.files.iter().map(|(_, size)| size).sum();
+    let dirs: u32 = dir
+        .dirs
+        .iter_mut()
+        .map(|(_, subdir)| add_files(rng, subdir))
+        .sum();
+    files + dirs
+}
+
+/// Writes the commands that list `dir` and its subdirectories.
+fn explore(rng: &mut impl Rng, dir: &Dir, output: &mut String) {
+    output.push_str("$ ls\n");
+    let mut entries: Vec<String> = dir
+        .dirs
+        .iter()
+        .map(|(name, _)| format!("dir {name}\n"))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{size} {name}\n")),
+        )
+        .collect();
+    entries.shuffle(rng);
+    entries.iter().for_each(|e| output.push_str(e));
+
+    for (name, subdir) in &dir.dirs {
+        output.push_str(&format!("$ cd {name}\n"));
+        explore(rng, subdir, output);
+        output.push_str("$ cd ..\n");
+    }
+}
+
+fn unique_name(rng: &mut impl Rng, dir: &Dir, is_file: bool) -> String {
+    let taken: HashSet<&str> = dir
+        .files
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .chain(dir.dirs.iter().map(|(name, _)| name.as_str()))
+        .collect();
+
+    loop {
+        let len = rng.gen_range(1..=8);
+        let mut name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if is_file && rng.gen() {
+            name.push_str([".txt", ".dat", ".log", ".cfg"].choose(rng).unwrap());
+        }
+
+        if !taken.contains(name.as_str()) {
+            return name;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day7;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day7.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Day 7: No Space Left On Device
+
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+
+pub mod generate;
+
+enum Item<'a> {
+    File { size: u32 },
+    Dir { content: HashMap<&'a str, Item<'a>> },
+}
+
+impl<'a> Item<'a> {
+    fn root() -> Self {
+        Self::Dir {
+            content: HashMap::new(),
+        }
+    }
+
+    fn get_item(&mut self, mut path: &[&str]) -> Option<&mut Self> {
+        let mut current = self;
+        while !path.is_empty() {
+            match current {
+                Self::File { .. } => return None,
+                Self::Dir { content } => {
+                    let name = path[0];
+                    path = &path[1..];
+
+                    current = content.get_mut(name)?;
+                }
+            }
+        }
+
+        Some(current)
+    }
+
+    fn add_file(&mut self, name: &'a str, size: u32) {
+        if let Self::Dir { content } = self {
+            content.insert(name, Self::File { size });
+        }
+    }
+
+    fn add_dir(&mut self, name: &'a str) {
+        if let Self::Dir { content } = self {
+            content.insert(
+                name,
+                Self::Dir {
+                    content: HashMap::new(),
+                },
+            );
+        }
+    }
+
+    fn reduce_size<F: FnMut(u32)>(&self, cb: &mut F) -> u32 {
+        match self {
+            &Self::File { size } => size,
+            Self::Dir { content } => {
+                let size = content.values().map(|i| i.reduce_size(cb)).sum();
+                cb(size);
+                size
+            }
+        }
+    }
+}
+
+fn build_tree(input: &str) -> Result<Item<'_>, ParseError> {
+    let mut root = Item::root();
+    let mut path = Vec::new();
+
+    let mut lines = parse::lines(input);
+
+    // the first line must be “cd /”, which can be skipped, because we are already at the root
+    let mut first = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(1, "\"$ cd /\""))?;
+    first.expect("$ cd /")?;
+    first.end()?;
+
+    for mut line in lines {
+        if line.eat("$ ") {
+            if line.eat("cd ") {
+                match line.take_rest() {
+                    ".." => {
+                        path.pop();
+                    }
+                    dir => path.push(dir),
+                }
+            } else {
+                // ignore “$ ls”
+                line.expect("ls")?;
+                line.end()?;
+            }
+        } else if line.eat("dir ") {
+            if let Some(item) = root.get_item(&path) {
+                item.add_dir(line.take_rest());
+            }
+        } else {
+            let size = line.integer()?;
+            line.expect(" ")?;
+            if let Some(item) = root.get_item(&path) {
+                item.add_file(line.take_rest(), size)
+            }
+        }
+    }
+
+    Ok(root)
+}
+
+/// The total sizes of all directories in the file system.
+pub struct DirSizes {
+    sizes: Vec<u32>,
+    total: u32,
+}
+
+impl DirSizes {
+    /// The sizes of all directories, including the root, each counting the files of its subdirectories.
+    pub fn sizes(&self) -> &[u32] {
+        &self.sizes
+    }
+
+    /// The size of the root directory.
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = DirSizes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let root = build_tree(input)?;
+
+        let mut sizes = Vec::new();
+        let total = root.reduce_size(&mut |size| sizes.push(size));
+
+        Ok(DirSizes { sizes, total })
+    }
+
+    fn part1(&self, dirs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(dirs
+            .sizes
+            .iter()
+            .filter(|&&size| size <= 100_000)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(&self, dirs: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let min = dirs
+            .sizes
+            .iter()
+            .copied()
+            .filter(|&size| size + 40000000 >= dirs.total)
+            .min()
+            .unwrap_or(dirs.total);
+
+        Ok(Some(min.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn part1() {
+        let input = Day7.parse(SAMPLE).unwrap();
+        assert_eq!(Day7.part1(&input).unwrap(), 95437.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day7.parse(SAMPLE).unwrap();
+        assert_eq!(Day7.part2(&input).unwrap(), Some(24933642.into()));
+    }
+}
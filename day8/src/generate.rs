@@ -0,0 +1,32 @@
+//! Random tree height maps.
+
+use rand::Rng;
+
+/// Generates a square map of trees with `size` rows and columns.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size).map(|_| rng.gen_range('0'..='9')).collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day8;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day8.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
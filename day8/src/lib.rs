@@ -0,0 +1,121 @@
+//! Day 8: Treetop Tree House
+
+use std::collections::HashSet;
+
+use common::{parse::ParseError, Answer, Solution, SolveError};
+use grid::{DenseGrid, Direction, Grid, Point};
+
+pub mod generate;
+
+fn mark_visible(output: &mut HashSet<Point>, grid: &DenseGrid<u8>, start: Point, dir: Direction) {
+    let mut prev = 0; // zero byte is smaller than '0'
+    let mut p = start;
+
+    while let Some(&height) = grid.get(p) {
+        if height > prev {
+            output.insert(p);
+            prev = height;
+        }
+
+        p += dir.offset();
+    }
+}
+
+fn view_distance(grid: &DenseGrid<u8>, p: Point, dir: Direction) -> u32 {
+    let height = grid[p];
+    let mut count = 0;
+
+    for (_, &other) in grid.ray(p, dir) {
+        count += 1;
+
+        if other >= height {
+            break;
+        }
+    }
+
+    count
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = DenseGrid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        DenseGrid::parse(input, |p, b| {
+            if b.is_ascii_digit() {
+                Ok(b)
+            } else {
+                Err(grid::unexpected_cell(p, b, "a tree height (0-9)"))
+            }
+        })
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, SolveError> {
+        let mut visible = HashSet::new();
+
+        for x in 0..grid.width() {
+            mark_visible(&mut visible, grid, Point::new(x, 0), Direction::Down);
+            mark_visible(
+                &mut visible,
+                grid,
+                Point::new(x, grid.height() - 1),
+                Direction::Up,
+            );
+        }
+
+        for y in 0..grid.height() {
+            mark_visible(&mut visible, grid, Point::new(0, y), Direction::Right);
+            mark_visible(
+                &mut visible,
+                grid,
+                Point::new(grid.width() - 1, y),
+                Direction::Left,
+            );
+        }
+
+        Ok(visible.len().into())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let max_score = grid
+            .iter()
+            .map(|(p, _)| {
+                Direction::ALL
+                    .iter()
+                    .map(|&dir| view_distance(grid, p, dir))
+                    .product::<u32>()
+            })
+            .max()
+            .unwrap_or(0);
+
+        Ok(Some(max_score.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn part1() {
+        let input = Day8.parse(SAMPLE).unwrap();
+        assert_eq!(Day8.part1(&input).unwrap(), 21.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day8.parse(SAMPLE).unwrap();
+        assert_eq!(Day8.part2(&input).unwrap(), Some(8.into()));
+    }
+}
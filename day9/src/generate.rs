@@ -0,0 +1,30 @@
+//! Random series of motions.
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Generates `size` motions of the head.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let direction = ['U', 'D', 'L', 'R'].choose(rng).unwrap();
+            format!("{direction} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{DynSolution, Part};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::Day9;
+
+    #[test]
+    fn generated_input_is_valid() {
+        for seed in 0..4 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            Day9.solve(&input, &Part::ALL).unwrap();
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Day 9: Rope Bridge
+
+use std::collections::HashSet;
+
+use common::{
+    parse::{self, ParseError},
+    Answer, Solution, SolveError,
+};
+use visualize::Frame;
+
+pub mod generate;
+
+/// A rope of knots, starting with the head.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    knots: Vec<(i32, i32)>,
+}
+
+impl Rope {
+    /// Creates a rope of `len` knots, which all start at the origin.
+    pub fn new(len: usize) -> Self {
+        assert!(len > 0, "a rope needs at least one knot");
+        Self {
+            knots: vec![(0, 0); len],
+        }
+    }
+
+    pub fn knots(&self) -> &[(i32, i32)] {
+        &self.knots
+    }
+
+    /// Moves the head by one step and lets each following knot catch up with the knot in front of it.
+    pub fn move_head(&mut self, dx: i32, dy: i32) {
+        self.knots[0].0 += dx;
+        self.knots[0].1 += dy;
+
+        for i in 1..self.knots.len() {
+            let front = self.knots[i - 1];
+            let mut back = self.knots[i];
+
+            let diff = (front.0 - back.0).pow(2) + (front.1 - back.1).pow(2);
+            if diff > 2 {
+                back.0 += (front.0 - back.0).signum();
+                back.1 += (front.1 - back.1).signum();
+            }
+
+            self.knots[i] = back;
+        }
+    }
+
+    pub fn tail(&self) -> (i32, i32) {
+        self.knots[self.knots.len() - 1]
+    }
+}
+
+/// A rope together with all positions its tail has visited.
+#[derive(Debug, Clone)]
+pub struct State {
+    rope: Rope,
+    visited: HashSet<(i32, i32)>,
+}
+
+impl State {
+    pub fn new(rope: Rope) -> Self {
+        let visited = [rope.tail()].into();
+        Self { rope, visited }
+    }
+
+    pub fn rope(&self) -> &Rope {
+        &self.rope
+    }
+
+    pub fn move_head(&mut self, dx: i32, dy: i32) {
+        self.rope.move_head(dx, dy);
+        self.visited.insert(self.rope.tail());
+        visualize::emit(|| self.render());
+    }
+
+    /// Renders the knots ('H' for the head, then numbered from 1) on top of the positions visited by the tail ('#'),
+    /// with the y axis pointing up.
+    fn render(&self) -> Frame {
+        let knots = &self.rope.knots;
+        let all = || self.visited.iter().chain(knots);
+        let (min_x, max_x) = (
+            all().map(|p| p.0).min().unwrap(),
+            all().map(|p| p.0).max().unwrap(),
+        );
+        let (min_y, max_y) = (
+            all().map(|p| p.1).min().unwrap(),
+            all().map(|p| p.1).max().unwrap(),
+        );
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        Frame::from_fn(width, height, |x, y| {
+            let p = (min_x + x as i32, max_y - y as i32);
+            match knots.iter().position(|&k| k == p) {
+                Some(0) => 'H',
+                Some(i) => char::from_digit(i as u32 % 10, 10).unwrap(),
+                None if self.visited.contains(&p) => '#',
+                None => '.',
+            }
+        })
+    }
+
+    /// The number of different positions the tail has visited.
+    pub fn tail_visited(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+/// A single motion of the head: the direction to move in and the number of steps.
+pub type Motion = ((i32, i32), u32);
+
+/// Moves the head of the rope by all steps of the motions.
+pub fn run(state: &mut State, motions: &[Motion]) {
+    for &((dx, dy), amount) in motions {
+        for _ in 0..amount {
+            state.move_head(dx, dy);
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|mut line| {
+                let dir =
+                    line.one_of(&[("D", (0, -1)), ("R", (1, 0)), ("U", (0, 1)), ("L", (-1, 0))])?;
+                line.expect(" ")?;
+                let amount = line.integer()?;
+                line.end()?;
+
+                Ok((dir, amount))
+            })
+            .collect()
+    }
+
+    fn part1(&self, motions: &Self::Input) -> Result<Answer, SolveError> {
+        let mut state = State::new(Rope::new(2));
+        run(&mut state, motions);
+        Ok(state.tail_visited().into())
+    }
+
+    fn part2(&self, motions: &Self::Input) -> Result<Option<Answer>, SolveError> {
+        let mut state = State::new(Rope::new(10));
+        run(&mut state, motions);
+        Ok(Some(state.tail_visited().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    #[test]
+    fn part1() {
+        let input = Day9.parse(SAMPLE).unwrap();
+        assert_eq!(Day9.part1(&input).unwrap(), 13.into());
+    }
+
+    #[test]
+    fn part2() {
+        let input = Day9.parse(SAMPLE).unwrap();
+        assert_eq!(Day9.part2(&input).unwrap(), Some(1.into()));
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let input = Day9
+            .parse(
+                "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+",
+            )
+            .unwrap();
+        assert_eq!(Day9.part2(&input).unwrap(), Some(36.into()));
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::{Index, IndexMut};
+
+use common::parse::ParseError;
+
+use crate::{Bounds, Grid, Point};
+
+/// A grid with a cell at every point of a `width` by `height` rectangle whose top left corner is the origin.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DenseGrid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    pub fn new(width: i32, height: i32, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |_| value.clone())
+    }
+
+    pub fn from_fn(width: i32, height: i32, f: impl FnMut(Point) -> T) -> Self {
+        let cells = if width > 0 && height > 0 {
+            Bounds::from_size(width, height).points().map(f).collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, converting each byte (together with its position) using `f`.
+    ///
+    /// Fails if `f` fails or if the lines do not all have the same length.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(Point, u8) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let len = line.len() as i32;
+            let width = *width.get_or_insert(len);
+            if len != width {
+                let column = len.min(width) as usize + 1;
+                let error = ParseError::new(y + 1, column, format!("a row of width {width}"));
+                return Err(if len > width {
+                    error.found(format!("a row of width {len}"))
+                } else {
+                    error
+                });
+            }
+
+            for (x, b) in line.bytes().enumerate() {
+                cells.push(f(Point::new(x as i32, y as i32), b)?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        let index = self.index(p)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Maps a point outside of the grid back into it, as if the grid was repeated infinitely in each direction.
+    pub fn wrap(&self, p: Point) -> Point {
+        Bounds::from_size(self.width, self.height).wrap(p)
+    }
+
+    /// All points of the grid together with their cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i as i32 % width, i as i32 / width), cell))
+    }
+
+    /// Adds rows at the bottom until the grid has the given height.
+    pub fn grow_to_height(&mut self, height: i32, value: T)
+    where
+        T: Clone,
+    {
+        if height > self.height {
+            self.cells
+                .resize(self.width as usize * height as usize, value);
+            self.height = height;
+        }
+    }
+
+    fn index(&self, p: Point) -> Option<usize> {
+        if p.x >= 0 && p.x < self.width && p.y >= 0 && p.y < self.height {
+            Some(p.y as usize * self.width as usize + p.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Grid for DenseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, p: Point) -> Option<&T> {
+        self.index(p).map(|i| &self.cells[i])
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (!self.cells.is_empty()).then(|| Bounds::from_size(self.width, self.height))
+    }
+}
+
+impl<T> Index<Point> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for DenseGrid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside of the grid"))
+    }
+}
@@ -0,0 +1,145 @@
+//! Two-dimensional grids as they appear in many puzzles.
+//!
+//! [`DenseGrid`] stores a value for every point of a rectangle and is meant for maps that are read from the input,
+//! while [`SparseGrid`] only stores the points that are set and can grow in any direction. Both implement [`Grid`],
+//! which provides neighbour iteration and rendering back to text.
+
+use common::parse::ParseError;
+pub use dense::DenseGrid;
+pub use point::{Bounds, Direction, Point};
+pub use sparse::SparseGrid;
+
+mod dense;
+mod point;
+mod sparse;
+
+/// An error for an unexpected byte `cell` at `p` while parsing a grid.
+pub fn unexpected_cell(p: Point, cell: u8, expected: &str) -> ParseError {
+    ParseError::unexpected(p.y as usize + 1, p.x as usize + 1, cell as char, expected)
+}
+
+pub trait Grid {
+    type Cell;
+
+    /// Returns the cell at `p`, or `None` if there is no cell at this point.
+    fn get(&self, p: Point) -> Option<&Self::Cell>;
+
+    /// The smallest bounds containing all cells, or `None` if the grid is empty.
+    fn bounds(&self) -> Option<Bounds>;
+
+    fn contains(&self, p: Point) -> bool {
+        self.get(p).is_some()
+    }
+
+    /// The orthogonally adjacent points of `p` that have a cell.
+    fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The orthogonally and diagonally adjacent points of `p` that have a cell.
+    fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().into_iter().filter(|&n| self.contains(n))
+    }
+
+    /// The cells in direction `dir` of `start` (excluding `start` itself), up to the first point without a cell.
+    fn ray(&self, start: Point, dir: Direction) -> impl Iterator<Item = (Point, &Self::Cell)> + '_ {
+        let offset = dir.offset();
+        let mut p = start;
+        std::iter::from_fn(move || {
+            p += offset;
+            self.get(p).map(|cell| (p, cell))
+        })
+    }
+
+    /// Renders the bounds of the grid as text, one line per row and without a trailing newline.
+    ///
+    /// `f` is called with `None` for points without a cell.
+    fn render(&self, mut f: impl FnMut(Option<&Self::Cell>) -> char) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+
+        let mut result = String::new();
+        for p in bounds.points() {
+            if p.x == bounds.min.x && p.y != bounds.min.y {
+                result.push('\n');
+            }
+
+            result.push(f(self.get(p)));
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+#..#
+.#..
+..##";
+
+    #[test]
+    fn dense_parse_and_render() {
+        let grid = DenseGrid::parse(MAP, |_, b| Ok(b == b'#')).unwrap();
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(grid.get(Point::new(4, 0)), None);
+        assert_eq!(
+            grid.render(|c| if c == Some(&true) { '#' } else { '.' }),
+            MAP
+        );
+    }
+
+    #[test]
+    fn sparse_parse_and_render() {
+        let grid = SparseGrid::parse(MAP, |_, b| Ok((b == b'#').then_some(()))).unwrap();
+        assert_eq!(grid.len(), 5);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point::new(0, 0),
+                max: Point::new(3, 2)
+            })
+        );
+        assert_eq!(grid.render(|c| if c.is_some() { '#' } else { '.' }), MAP);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = DenseGrid::parse(MAP, |_, b| Ok(b)).unwrap();
+        assert_eq!(grid.neighbors4(Point::ORIGIN).count(), 2);
+        assert_eq!(grid.neighbors8(Point::ORIGIN).count(), 3);
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn ray() {
+        let grid = DenseGrid::parse(MAP, |_, b| Ok(b)).unwrap();
+        let cells: Vec<_> = grid
+            .ray(Point::new(0, 2), Direction::Right)
+            .map(|(_, &b)| b)
+            .collect();
+        assert_eq!(cells, b".##");
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let error = DenseGrid::parse("..\n...\n", |_, b| Ok(b)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row of width 2, found a row of width 3"
+        );
+    }
+
+    #[test]
+    fn wrap() {
+        let bounds = Bounds::from_size(4, 3);
+        assert_eq!(bounds.wrap(Point::new(-1, 3)), Point::new(3, 0));
+        assert_eq!(bounds.wrap(Point::new(9, -7)), Point::new(1, 2));
+    }
+}
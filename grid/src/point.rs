@@ -0,0 +1,181 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// A position on a grid.
+///
+/// `x` grows to the right and `y` grows downwards, like rows and columns of text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The four orthogonally adjacent points.
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|d| self + d.offset())
+    }
+
+    /// The eight orthogonally and diagonally adjacent points, clockwise starting at the top left.
+    pub fn neighbors8(self) -> [Point; 8] {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .map(|(x, y)| self + Point::new(x, y))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// An inclusive, axis-aligned rectangle of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    /// The bounds of a `width` by `height` rectangle with its top left corner at the origin.
+    ///
+    /// Both `width` and `height` must be positive.
+    pub fn from_size(width: i32, height: i32) -> Self {
+        Self {
+            min: Point::ORIGIN,
+            max: Point::new(width - 1, height - 1),
+        }
+    }
+
+    /// The smallest bounds that contain all the given points, or `None` if there are no points.
+    pub fn enclosing(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |bounds, p| Self {
+                min: Point::new(bounds.min.x.min(p.x), bounds.min.y.min(p.y)),
+                max: Point::new(bounds.max.x.max(p.x), bounds.max.y.max(p.y)),
+            },
+        ))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() as i64 * self.height() as i64
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Maps a point outside of the bounds back into them, as if the rectangle was repeated infinitely in each
+    /// direction.
+    pub fn wrap(&self, p: Point) -> Point {
+        Point::new(
+            self.min.x + (p.x - self.min.x).rem_euclid(self.width()),
+            self.min.y + (p.y - self.min.y).rem_euclid(self.height()),
+        )
+    }
+
+    /// All points within the bounds, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
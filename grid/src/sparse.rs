@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use common::parse::ParseError;
+
+use crate::{Bounds, Grid, Point};
+
+/// A grid that only stores the points that have a cell, without any limits on the coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a grid with one row per line, converting each byte (together with its position) using `f`, which
+    /// returns `None` for points without a cell.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(Point, u8) -> Result<Option<T>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, b) in line.bytes().enumerate() {
+                let p = Point::new(x as i32, y as i32);
+                if let Some(cell) = f(p, b)? {
+                    grid.insert(p, cell);
+                }
+            }
+        }
+
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Sets the cell at `p`, returning the previous one.
+    pub fn insert(&mut self, p: Point, cell: T) -> Option<T> {
+        self.cells.insert(p, cell)
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// All points that have a cell, in arbitrary order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// All points that have a cell together with their cells, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        Bounds::enclosing(self.points())
+    }
+}
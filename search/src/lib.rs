@@ -0,0 +1,275 @@
+//! Shortest path searches over implicit graphs.
+//!
+//! Instead of building a graph up front, the searches take a closure that returns the neighbours of a node, so nodes
+//! can be anything from grid points to whole puzzle states. All searches accept several start nodes (which all have
+//! a distance of zero) and stop as soon as a goal node is reached. The returned [`Search`] keeps the distances and
+//! predecessors of all visited nodes, so it can be used for flood fills as well as for path reconstruction.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cost of an edge or a path. `Default` must be the cost of an empty path (zero).
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// The result of a search.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal node that was reached, or `None` if no goal is reachable.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The distance from the nearest start node to the goal, or `None` if no goal is reachable.
+    pub fn cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The shortest distance from any start node to `node`, or `None` if the node was not reached.
+    ///
+    /// Only the distances of nodes that were reached before the search stopped at the goal are known.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// All nodes that were reached by the search, in arbitrary order.
+    pub fn visited(&self) -> impl Iterator<Item = &N> {
+        self.distances.keys()
+    }
+
+    /// The shortest path from a start node to the goal (including both), or `None` if no goal is reachable.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// The shortest path from a start node to `node` (including both), or `None` if the node was not reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, where every edge has a cost of one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, u32>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for next in neighbors(&node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm: `neighbors` returns the adjacent nodes together with the cost of the edge to them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search, which explores the nodes in the order of their distance plus `heuristic`.
+///
+/// The heuristic must never overestimate the remaining cost to the nearest goal, otherwise the found path is not
+/// guaranteed to be the shortest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        search.distances.insert(start.clone(), C::default());
+        queue.push(Candidate {
+            estimate: heuristic(&start),
+            distance: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Candidate { distance, node, .. }) = queue.pop() {
+        if search.distances[&node] < distance {
+            // a shorter path to this node was found after it was queued
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, cost) in neighbors(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&d| next_distance < d)
+            {
+                search.distances.insert(next.clone(), next_distance);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push(Candidate {
+                    estimate: next_distance + heuristic(&next),
+                    distance: next_distance,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// A queued node, ordered so that the `BinaryHeap` pops the lowest estimate first.
+struct Candidate<N, C> {
+    estimate: C,
+    distance: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes 0..=10 where each node `n` leads to `n + 1` (cost 1) and `n + 3` (cost 5).
+    fn line(n: &u32) -> Vec<(u32, u32)> {
+        [(n + 1, 1), (n + 3, 5)]
+            .into_iter()
+            .filter(|&(m, _)| m <= 10)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+        let search = bfs([0], |&n| line(&n).into_iter().map(|(m, _)| m), |&n| n == 10);
+        assert_eq!(search.cost(), Some(4));
+
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!((path[0], path[4]), (0, 10));
+    }
+
+    #[test]
+    fn dijkstra_uses_costs() {
+        let search = dijkstra([0], line, |&n| n == 7);
+        assert_eq!(search.cost(), Some(7));
+        assert_eq!(search.path().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let search = astar([0], line, |&n| 10 - n, |&n| n == 10);
+        assert_eq!(search.cost(), Some(10));
+        assert_eq!(search.goal(), Some(&10));
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let search = bfs(
+            [0, 8],
+            |&n| line(&n).into_iter().map(|(m, _)| m),
+            |&n| n == 10,
+        );
+        assert_eq!(search.cost(), Some(2));
+        assert_eq!(search.path(), Some(vec![8, 9, 10]));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let search = bfs([0], |&n| line(&n).into_iter().map(|(m, _)| m), |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.cost(), None);
+        assert_eq!(search.visited().count(), 11);
+        assert_eq!(search.distance(&5), Some(3));
+    }
+}
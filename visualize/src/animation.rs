@@ -0,0 +1,85 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter},
+    path::PathBuf,
+    time::Duration,
+};
+
+use gif::{Encoder, Repeat};
+
+use crate::{rasterize, Frame, FrameSink};
+
+/// Collects the frames into an animated GIF, which is written when the sink is finished.
+///
+/// The frames are buffered, because the size of the animation is the size of the largest frame. Smaller frames are
+/// drawn at the top left corner on a black background.
+pub struct Gif {
+    path: PathBuf,
+    scale: usize,
+    delay: Duration,
+    frames: Vec<Frame>,
+}
+
+impl Gif {
+    /// Each character of a frame is drawn as a `scale` by `scale` square, and each frame is shown for `delay`.
+    pub fn new(path: impl Into<PathBuf>, scale: usize, delay: Duration) -> Self {
+        Self {
+            path: path.into(),
+            scale,
+            delay,
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl FrameSink for Gif {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames.push(frame.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let width = self.frames.iter().map(Frame::width).max().unwrap_or(0);
+        let height = self.frames.iter().map(Frame::height).max().unwrap_or(0);
+        let size = |cells: usize| {
+            u16::try_from(cells * self.scale)
+                .map_err(|_| io::Error::other("frames are too large for a GIF"))
+        };
+        let (image_width, image_height) = (size(width)?, size(height)?);
+
+        // the palette contains the colors of all characters that appear in any frame
+        let mut palette: Vec<[u8; 3]> = vec![[0, 0, 0]];
+        let mut indices = HashMap::from([([0, 0, 0], 0)]);
+
+        let file = BufWriter::new(File::create(&self.path)?);
+        let mut encoder =
+            Encoder::new(file, image_width, image_height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for frame in &self.frames {
+            let padded = Frame::from_fn(width, height, |x, y| frame.get(x, y).unwrap_or(' '));
+            let mut pixels = Vec::new();
+            for color in rasterize(&padded, self.scale) {
+                let index = *indices.entry(color).or_insert_with(|| {
+                    palette.push(color);
+                    palette.len() - 1
+                });
+                let index = u8::try_from(index)
+                    .map_err(|_| io::Error::other("frames use more than 256 colors"))?;
+                pixels.push(index);
+            }
+
+            let mut image =
+                gif::Frame::from_indexed_pixels(image_width, image_height, pixels, None);
+            image.palette = Some(palette.concat());
+            // the delay is given in hundredths of a second
+            image.delay = (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+            encoder.write_frame(&image).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::{Frame, FrameSink};
+
+/// Shows the frames in the terminal, replacing the previous frame and pausing `delay` after each frame.
+pub struct Ascii<W> {
+    out: W,
+    delay: Duration,
+}
+
+impl<W: Write> Ascii<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self { out, delay }
+    }
+}
+
+impl<W: Write> FrameSink for Ascii<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        // move the cursor to the top left corner and clear the screen
+        write!(self.out, "\x1b[H\x1b[2J")?;
+        for row in frame.rows() {
+            writeln!(self.out, "{row}")?;
+        }
+        self.out.flush()?;
+
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+//! Frames of simulations, for watching and debugging them.
+//!
+//! Simulations call [`emit`] with a closure that renders the current state as a [`Frame`] of characters. The closure
+//! is only called while a [`FrameSink`] is installed with [`record`], so emitting frames costs nothing during normal
+//! runs. The sinks show the frames in the terminal ([`Ascii`]), write them as numbered images ([`Ppm`]) or collect
+//! them into an animation ([`Gif`]).
+
+use std::{cell::RefCell, io};
+
+pub use animation::Gif;
+pub use ascii::Ascii;
+pub use ppm::Ppm;
+
+mod animation;
+mod ascii;
+mod ppm;
+
+/// A rectangle of characters, like the rendering of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> char) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a frame with one row per line of `text`, padding shorter lines with spaces.
+    pub fn from_text(text: &str) -> Self {
+        let lines: Vec<Vec<char>> = text.lines().map(|l| l.chars().collect()).collect();
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        Self::from_fn(width, lines.len(), |x, y| {
+            lines[y].get(x).copied().unwrap_or(' ')
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// The rows of the frame, each as a string.
+    pub fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        self.cells
+            .chunks(self.width.max(1))
+            .take(self.height)
+            .map(|row| row.iter().collect())
+    }
+}
+
+/// A destination for the frames of a simulation.
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called after the last frame, e.g. to write out buffered frames.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl FrameSink for Vec<Frame> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+struct Recording {
+    sink: Box<dyn FrameSink>,
+    error: Option<io::Error>,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `sink` receiving all frames that are emitted on the current thread, then finishes the sink.
+///
+/// Returns the result of `f` together with the first error of the sink. After an error, no more frames are rendered.
+pub fn record<R>(sink: Box<dyn FrameSink>, f: impl FnOnce() -> R) -> (R, io::Result<()>) {
+    let previous = RECORDING.with(|r| r.replace(Some(Recording { sink, error: None })));
+    let result = f();
+    let recording = RECORDING.with(|r| r.replace(previous)).unwrap();
+
+    let Recording { mut sink, error } = recording;
+    let status = match error {
+        Some(error) => Err(error),
+        None => sink.finish(),
+    };
+
+    (result, status)
+}
+
+/// Whether frames are currently being recorded, for simulations that need to collect extra state for their frames.
+pub fn is_enabled() -> bool {
+    RECORDING.with(|r| r.borrow().as_ref().is_some_and(|r| r.error.is_none()))
+}
+
+/// Sends the frame rendered by `render` to the installed sink, if there is one.
+pub fn emit(render: impl FnOnce() -> Frame) {
+    RECORDING.with(|r| {
+        let mut recording = r.borrow_mut();
+        if let Some(recording) = recording.as_mut().filter(|r| r.error.is_none()) {
+            if let Err(error) = recording.sink.frame(&render()) {
+                recording.error = Some(error);
+            }
+        }
+    });
+}
+
+/// The color used for a character in image outputs.
+///
+/// Common characters of the puzzles have fixed colors, all others get a color derived from the character.
+pub fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' => [0, 0, 0],
+        '.' => [40, 40, 48],
+        '#' => [210, 210, 210],
+        'o' => [230, 190, 60],
+        '@' => [220, 90, 60],
+        '+' => [255, 60, 60],
+        'H' => [255, 80, 80],
+        'E' => [80, 220, 80],
+        '^' | 'v' | '<' | '>' | '2'..='9' => [90, 150, 255],
+        _ => {
+            let n = c as u32;
+            [
+                (n.wrapping_mul(67) % 200 + 55) as u8,
+                (n.wrapping_mul(131) % 200 + 55) as u8,
+                (n.wrapping_mul(197) % 200 + 55) as u8,
+            ]
+        }
+    }
+}
+
+/// Converts a frame into the colors of its pixels, row by row, drawing each character as a `scale` by `scale`
+/// square.
+fn rasterize(frame: &Frame, scale: usize) -> Vec<[u8; 3]> {
+    let mut pixels = Vec::with_capacity(frame.width * frame.height * scale * scale);
+    for row in frame.cells.chunks(frame.width.max(1)).take(frame.height) {
+        for _ in 0..scale {
+            for &c in row {
+                pixels.extend(std::iter::repeat_n(color(c), scale));
+            }
+        }
+    }
+
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_from_text() {
+        let frame = Frame::from_text("#.\n.##\n");
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.get(2, 0), Some(' '));
+        assert_eq!(frame.get(2, 1), Some('#'));
+        assert_eq!(frame.get(3, 1), None);
+        assert_eq!(frame.rows().collect::<Vec<_>>(), ["#. ", ".##"]);
+    }
+
+    #[test]
+    fn emit_without_sink() {
+        assert!(!is_enabled());
+        emit(|| panic!("frame must not be rendered without a sink"));
+    }
+
+    #[test]
+    fn record_frames() {
+        struct Shared(std::rc::Rc<RefCell<Vec<Frame>>>);
+
+        impl FrameSink for Shared {
+            fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+                self.0.borrow_mut().frame(frame)
+            }
+        }
+
+        let frames = std::rc::Rc::default();
+        let (result, status) = record(Box::new(Shared(std::rc::Rc::clone(&frames))), || {
+            assert!(is_enabled());
+            emit(|| Frame::from_text("a"));
+            emit(|| Frame::from_text("b"));
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert!(status.is_ok());
+        assert_eq!(frames.borrow().len(), 2);
+        assert!(!is_enabled());
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use crate::{rasterize, Frame, FrameSink};
+
+/// Writes each frame as a binary PPM image, numbered from 1: `{dir}/{prefix}-00001.ppm`, `{dir}/{prefix}-00002.ppm`, ...
+pub struct Ppm {
+    dir: PathBuf,
+    prefix: String,
+    scale: usize,
+    count: usize,
+}
+
+impl Ppm {
+    /// Each character of a frame is drawn as a `scale` by `scale` square.
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>, scale: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            prefix: prefix.into(),
+            scale,
+            count: 0,
+        }
+    }
+}
+
+/// Writes `frame` as a binary PPM (P6) image.
+pub fn write_ppm(out: &mut impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    writeln!(
+        out,
+        "P6\n{} {}\n255",
+        frame.width() * scale,
+        frame.height() * scale
+    )?;
+    for pixel in rasterize(frame, scale) {
+        out.write_all(&pixel)?;
+    }
+
+    Ok(())
+}
+
+impl FrameSink for Ppm {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.count += 1;
+        let path = self
+            .dir
+            .join(format!("{}-{:05}.ppm", self.prefix, self.count));
+
+        let mut out = BufWriter::new(File::create(path)?);
+        write_ppm(&mut out, frame, self.scale)?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color;
+
+    #[test]
+    fn scaled_image() {
+        let mut out = Vec::new();
+        write_ppm(&mut out, &Frame::from_text("#."), 2).unwrap();
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+
+        let pixels: Vec<[u8; 3]> = out[header.len()..]
+            .chunks(3)
+            .map(|p| p.try_into().unwrap())
+            .collect();
+        let (wall, ground) = (color('#'), color('.'));
+        assert_eq!(
+            pixels,
+            [wall, wall, ground, ground, wall, wall, ground, ground]
+        );
+    }
+}